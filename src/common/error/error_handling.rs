@@ -31,6 +31,11 @@ pub fn handle_result_error(err: MError) {
             message.push_str(&s);
             println!("{}", message.red());
         }
+        MError::SemanticError(s) => {
+            let mut message = "Error type: Semantic error\nError message: ".to_string();
+            message.push_str(&s);
+            println!("{}", message.red());
+        }
     }
     println!("{}", "\n<------- End of message ------->".yellow());
     println!("Objects were mapped unsuccessfully");
@@ -1,4 +1,4 @@
-#[allow(dead_code)]
+#[allow(dead_code, clippy::enum_variant_names)]
 #[derive(Debug)]
 pub enum MError {
     ParseError(String),
@@ -6,4 +6,5 @@ pub enum MError {
     GenError(String),
     ClassError(String),
     DeparseError(String),
+    SemanticError(String),
 }
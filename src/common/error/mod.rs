@@ -2,6 +2,7 @@ mod error;
 mod error_handling;
 mod error_types;
 
+#[allow(unused_imports)]
 pub use error::*;
 pub use error_handling::*;
 pub use error_types::*;
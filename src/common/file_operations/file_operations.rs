@@ -1,5 +1,7 @@
 use crate::common::{handle_result_error, MError};
+use crate::parser::parse_language;
 use colored::Colorize;
+use std::fs;
 use std::fs::File;
 use std::io::prelude::*;
 use std::path::Path;
@@ -12,13 +14,13 @@ pub fn write_file(file: &mut File, buffer: &str) {
 pub fn open_file(file: &str) -> File {
     println!("{} {:?}", "About to open the file".yellow(), file);
     let input_path = Path::new(file);
-    match File::open(&input_path) {
+    match File::open(input_path) {
         Ok(f) => f,
-        Err(e) => {
+        Err(_e) => {
             let mut message = "Error opening file: ".to_string();
             message.push_str(file);
             handle_result_error(MError::GenError(message));
-            Err(e).unwrap()
+            panic!()
         }
     }
 }
@@ -26,18 +28,34 @@ pub fn open_file(file: &str) -> File {
 pub fn create_file(file: &str) -> File {
     println!("{} {:?}", "Creating the file".yellow(), file);
     let input_path = Path::new(file);
-    match File::create(&input_path) {
+    if let Some(directory) = input_path.parent() {
+        if fs::create_dir_all(directory).is_err() {
+            let mut message = "Error creating the directory for file: ".to_string();
+            message.push_str(file);
+            handle_result_error(MError::GenError(message));
+        }
+    }
+    match File::create(input_path) {
         Ok(f) => f,
-        Err(e) => {
+        Err(_e) => {
             let mut message = "Error creating file: ".to_string();
             message.push_str(file);
             handle_result_error(MError::GenError(message));
-            Err(e).unwrap()
+            panic!()
         }
     }
 }
 
-pub fn get_file_buffer(file: &mut File, filename: &str) -> Vec<String> {
+pub fn get_file_buffer(
+    file: &mut File,
+    filename: &str,
+    keep_comments: bool,
+) -> Vec<(String, usize)> {
+    let buffer = read_file(file, filename);
+    tokenize(&buffer, keep_comments)
+}
+
+pub fn read_file(file: &mut File, filename: &str) -> String {
     let mut buffer = String::new();
     match file.read_to_string(&mut buffer) {
         Ok(_s) => (),
@@ -47,10 +65,310 @@ pub fn get_file_buffer(file: &mut File, filename: &str) -> Vec<String> {
             handle_result_error(MError::GenError(message))
         }
     }
-    let mut buffer_vector: Vec<&str> = buffer
-        .split(|c| c == '\n' || c == ' ' || c == '\t')
+    buffer
+}
+
+// Splits a schema into whitespace separated tokens, each with the line it starts on. `//` and
+// `/* */` comments are dropped unless `keep_comments` is set, while each `///` doc comment
+// line is kept as a single token starting with `///`.
+pub fn tokenize(buffer: &str, keep_comments: bool) -> Vec<(String, usize)> {
+    let chars: Vec<char> = buffer.chars().collect();
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut angle_depth = 0;
+    let mut square_depth = 0;
+    let mut paren_depth = 0;
+    let mut in_header = false;
+    let mut line = 1;
+    let mut start_line = 1;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        if current.is_empty() {
+            start_line = line;
+        }
+        if c == '/' && next == Some('/') {
+            push_token(&mut tokens, &mut current, start_line);
+            let line_end = chars[i..]
+                .iter()
+                .position(|&c| c == '\n')
+                .map_or(chars.len(), |p| i + p);
+            let comment: String = chars[i..line_end].iter().collect();
+            if comment.starts_with("///") && !comment.starts_with("////") {
+                let mut doc = "///".to_string();
+                doc.push_str(comment[3..].trim());
+                tokens.push((doc, line));
+            } else if keep_comments {
+                tokens.push((comment.trim_end().to_string(), line));
+            }
+            i = line_end;
+            continue;
+        }
+        if c == '/' && next == Some('*') {
+            push_token(&mut tokens, &mut current, start_line);
+            let comment_line = line;
+            let comment_start = i;
+            i += 2;
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                if chars[i] == '\n' {
+                    line += 1;
+                }
+                i += 1;
+            }
+            i += 2;
+            if keep_comments {
+                let comment: String = chars[comment_start..i.min(chars.len())].iter().collect();
+                tokens.push((comment, comment_line));
+            }
+            continue;
+        }
+        if c == '"' || c == '\'' {
+            // quoted literals keep their whitespace and may contain comment markers
+            current.push(c);
+            i += 1;
+            while i < chars.len() && chars[i] != c {
+                if chars[i] == '\\' && i + 1 < chars.len() {
+                    current.push(chars[i]);
+                    i += 1;
+                }
+                if chars[i] == '\n' {
+                    line += 1;
+                }
+                current.push(chars[i]);
+                i += 1;
+            }
+            if i < chars.len() {
+                current.push(c);
+            }
+            i += 1;
+            continue;
+        }
+        // `[rs,-c]` is split like `[ rs, -c ]` so the parser reads one language per token
+        if c == '['
+            && current.is_empty()
+            && tokens.last().map(|(t, _l)| t.as_str()) != Some("=")
+            && is_header_start(&chars[i + 1..])
+        {
+            tokens.push(("[".to_string(), line));
+            in_header = true;
+            i += 1;
+            continue;
+        }
+        if in_header && (c == ',' || c == ']') {
+            if c == ',' {
+                current.push(c);
+            }
+            push_token(&mut tokens, &mut current, start_line);
+            if c == ']' {
+                tokens.push(("]".to_string(), line));
+                in_header = false;
+            }
+            i += 1;
+            continue;
+        }
+        match c {
+            '<' => {
+                angle_depth += 1;
+                current.push(c);
+            }
+            '>' => {
+                if angle_depth > 0 {
+                    angle_depth -= 1;
+                }
+                current.push(c);
+            }
+            // a `[` directly followed by its content opens a literal such as `[1, 2]`,
+            // unlike the `[ rs, ts ]` language header
+            '[' if square_depth > 0 || next.is_some_and(|n| !n.is_whitespace()) => {
+                square_depth += 1;
+                current.push(c);
+            }
+            ']' if square_depth > 0 => {
+                square_depth -= 1;
+                current.push(c);
+            }
+            '(' => {
+                paren_depth += 1;
+                current.push(c);
+            }
+            ')' => {
+                if paren_depth > 0 {
+                    paren_depth -= 1;
+                }
+                current.push(c);
+            }
+            // generic arguments such as `Pair<K, V>` and annotation arguments such as
+            // `@length(1, 64)` stay a single token
+            '\n' | ' ' | '\t' | '\r' if angle_depth > 0 || square_depth > 0 || paren_depth > 0 => {}
+            '\n' | ' ' | '\t' | '\r' => push_token(&mut tokens, &mut current, start_line),
+            _ => current.push(c),
+        }
+        if c == '\n' {
+            line += 1;
+        }
+        i += 1;
+    }
+    push_token(&mut tokens, &mut current, start_line);
+    tokens
+}
+
+// a language, `+language` or `-language` follows the `[`, unlike array literals such as
+// `[1, 2]`, `[-1]` or `["a"]`
+fn is_header_start(rest: &[char]) -> bool {
+    let rest: Vec<char> = rest
+        .iter()
+        .copied()
+        .skip_while(|c| c.is_whitespace())
         .collect();
-    buffer_vector.retain(|&c| !c.is_empty());
-    let strings = buffer_vector.iter().map(|s| s.to_string()).collect();
-    strings
+    let word_start = match rest.first() {
+        Some('+') | Some('-') => 1,
+        _ => 0,
+    };
+    let word: String = rest[word_start..]
+        .iter()
+        .take_while(|c| c.is_alphanumeric() || **c == '_')
+        .collect();
+    let after_word = rest.get(word_start + word.chars().count());
+    if word_start == 1 {
+        return word.starts_with(|c: char| c.is_alphabetic());
+    }
+    parse_language(word.as_str()).is_some()
+        && after_word.is_some_and(|c| *c == ',' || *c == ']' || c.is_whitespace())
+}
+
+fn push_token(tokens: &mut Vec<(String, usize)>, current: &mut String, line: usize) {
+    if !current.is_empty() {
+        tokens.push((current.clone(), line));
+        current.clear();
+    }
+}
+
+pub fn is_comment(token: &str) -> bool {
+    token.starts_with("//") || token.starts_with("/*")
+}
+
+pub fn is_doc_comment(token: &str) -> bool {
+    token.starts_with("///")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_tokens(buffer: &str, keep_comments: bool) -> Vec<String> {
+        tokenize(buffer, keep_comments)
+            .into_iter()
+            .map(|(t, _l)| t)
+            .collect()
+    }
+
+    #[test]
+    fn splits_language_headers() {
+        assert_eq!(
+            get_tokens("[ rs, ts ]\nUser", false),
+            vec!["[", "rs,", "ts", "]", "User"]
+        );
+        assert_eq!(
+            get_tokens("[rs,ts]\nUser", false),
+            vec!["[", "rs,", "ts", "]", "User"]
+        );
+        assert_eq!(
+            get_tokens("targets [java,-c, +rs] A", false),
+            vec!["targets", "[", "java,", "-c,", "+rs", "]", "A"]
+        );
+        assert_eq!(get_tokens("[-c]", false), vec!["[", "-c", "]"]);
+    }
+
+    #[test]
+    fn keeps_array_literals_together() {
+        assert_eq!(
+            get_tokens("a: List<int> = [-1, 2], b: [int; 2] = [c, rs]", false),
+            vec![
+                "a:",
+                "List<int>",
+                "=",
+                "[-1,2],",
+                "b:",
+                "[int;2]",
+                "=",
+                "[c,rs]"
+            ]
+        );
+        assert_eq!(get_tokens("= [\"rs\"]", false), vec!["=", "[\"rs\"]"]);
+    }
+
+    #[test]
+    fn type_arguments_stay_one_token() {
+        assert_eq!(
+            get_tokens("page: Map<String, List<Pair<K, V>>>,\nnext: Page<T>", false),
+            vec!["page:", "Map<String,List<Pair<K,V>>>,", "next:", "Page<T>"]
+        );
+    }
+
+    #[test]
+    fn drops_comments_but_keeps_doc_comments() {
+        let buffer = "/// A user\npub User { // trailing\n/* block\ncomment */ name: String }";
+        assert_eq!(
+            get_tokens(buffer, false),
+            vec!["///A user", "pub", "User", "{", "name:", "String", "}"]
+        );
+        assert_eq!(
+            get_tokens(buffer, true),
+            vec![
+                "///A user",
+                "pub",
+                "User",
+                "{",
+                "// trailing",
+                "/* block\ncomment */",
+                "name:",
+                "String",
+                "}"
+            ]
+        );
+    }
+
+    #[test]
+    fn four_slashes_are_a_plain_comment() {
+        assert_eq!(get_tokens("//// note\nA", false), vec!["A"]);
+    }
+
+    #[test]
+    fn tokens_carry_the_line_they_start_on() {
+        let buffer = "/* one\ntwo */ A\n\n@min(\n0) b: int\n/// doc\nc";
+        assert_eq!(
+            tokenize(buffer, false),
+            vec![
+                ("A".to_string(), 2),
+                ("@min(0)".to_string(), 4),
+                ("b:".to_string(), 5),
+                ("int".to_string(), 5),
+                ("///doc".to_string(), 6),
+                ("c".to_string(), 7),
+            ]
+        );
+    }
+
+    #[test]
+    fn quoted_literals_keep_whitespace_and_comment_markers() {
+        let buffer = "name: String = \"a // b /* c */\", quote: char = ' '";
+        assert_eq!(
+            get_tokens(buffer, false),
+            vec![
+                "name:",
+                "String",
+                "=",
+                "\"a // b /* c */\",",
+                "quote:",
+                "char",
+                "=",
+                "' '"
+            ]
+        );
+        assert_eq!(
+            get_tokens("s: String = \"say \\\"hi\\\"\"", false),
+            vec!["s:", "String", "=", "\"say \\\"hi\\\"\""]
+        );
+    }
 }
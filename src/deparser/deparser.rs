@@ -1,15 +1,26 @@
 use crate::common::{create_file, handle_result_error, write_file, MError};
-use crate::models::{Access, Language};
-use crate::objects::Class;
+use crate::models::{Access, ArrayType, FieldType, Language};
+use crate::objects::{
+    get_c_type, get_doc_comment, get_java_import, get_java_tuple_record, get_rust_default_value,
+    get_rust_type, get_rust_type_alias, get_ts_type, is_c_sized_type, Class, Field,
+};
+use crate::user_env::UserEnv;
 use colored::Colorize;
 
 pub struct DeParser {
     pub objects: Vec<Class>,
+    // classes from every loaded schema file, used to resolve interfaces and generics
+    pub all_objects: Vec<Class>,
+    pub user_env: UserEnv,
 }
 
 impl DeParser {
-    pub fn new(objects: Vec<Class>) -> DeParser {
-        DeParser { objects }
+    pub fn new(objects: Vec<Class>, all_objects: Vec<Class>, user_env: UserEnv) -> DeParser {
+        DeParser {
+            objects,
+            all_objects,
+            user_env,
+        }
     }
 
     pub fn construct(&mut self) {
@@ -32,14 +43,69 @@ impl DeParser {
                         panic!()
                     }
                 };
-                let (output, file_extension) = match language {
-                    Language::JAVA => (construct_java_class(current_object), ".java"),
-                    Language::TYPESCRIPT => (construct_ts_class(current_object), ".ts"),
-                    Language::C => (construct_c_structs(current_object), ".c"),
-                    Language::RUST => (construct_rust_structs(current_object), ".rs"),
+                if current_object.is_interface() && *language == Language::C {
+                    print!("{}", "Skipping C output for interface ".yellow());
+                    println!("{:?}", current_object.get_name());
+                    continue;
+                }
+                if current_object.is_alias() && *language == Language::JAVA {
+                    print!("{}", "Inlining Java alias ".yellow());
+                    println!("{:?}", current_object.get_name());
+                    continue;
+                }
+                if current_object.is_const()
+                    && *language == Language::JAVA
+                    && find_java_constants(current_object, &self.all_objects)[0].get_name()
+                        != current_object.get_name()
+                {
+                    print!("{}", "Grouping Java constant ".yellow());
+                    println!("{:?} into Constants", current_object.get_name());
+                    continue;
+                }
+                if current_object.is_generic()
+                    && *language == Language::C
+                    && find_instantiations(current_object, &self.all_objects).is_empty()
+                {
+                    print!(
+                        "{}",
+                        "Skipping C output for uninstantiated generic ".yellow()
+                    );
+                    println!("{:?}", current_object.get_generic_name());
+                    continue;
+                }
+                // emitters only ever see the names used by the target language
+                let case = self.user_env.get_case(language);
+                let objects: Vec<Class> = self
+                    .all_objects
+                    .iter()
+                    .map(|o| o.with_language_names(language, &self.all_objects, &case))
+                    .collect();
+                let mut class = current_object
+                    .with_language_names(language, &self.all_objects, &case)
+                    .with_deprecation_notes(language);
+                if *language == Language::JAVA {
+                    class = class.with_inlined_aliases(&objects);
+                }
+                for field in current_object.fields.iter() {
+                    let name = field.get_name_for(language, &case);
+                    if language.is_keyword(name.as_str()) {
+                        print!("{}", "Escaping reserved keyword ".yellow());
+                        println!(
+                            "{:?} in {}.{} as {:?}",
+                            name,
+                            current_object.get_name(),
+                            field.get_name(),
+                            language.escape_identifier(name.as_str())
+                        );
+                    }
+                }
+                let output = match language {
+                    Language::JAVA => construct_java_file(&class, &objects),
+                    Language::TYPESCRIPT => construct_ts_class(&class, &objects),
+                    Language::C => construct_c_file(&class, &objects),
+                    Language::RUST => construct_rust_file(&class, &objects, &self.user_env),
                 };
-                let mut file_name = current_object.get_name();
-                file_name.push_str(file_extension);
+                let file_name = get_output_file_name(&class, language);
                 let mut output_file = create_file(file_name.as_str());
                 print!("{}", "Beginning to write to file ".blue());
                 println!("{:?}", file_name);
@@ -50,8 +116,182 @@ impl DeParser {
     }
 }
 
-fn construct_java_class(class: &Class) -> String {
+// Java and TypeScript files are placed in directories following the class namespace
+fn get_output_file_name(class: &Class, language: &Language) -> String {
+    let mut file_name = String::new();
+    if let Language::JAVA | Language::TYPESCRIPT = language {
+        for segment in class.get_namespace_segments() {
+            file_name.push_str(segment.as_str());
+            file_name.push('/');
+        }
+    }
+    if class.is_const() && *language == Language::JAVA {
+        file_name.push_str("Constants");
+    } else {
+        file_name.push_str(class.get_name().as_str());
+    }
+    match language {
+        Language::JAVA => file_name.push_str(".java"),
+        Language::TYPESCRIPT => file_name.push_str(".ts"),
+        Language::C => file_name.push_str(".c"),
+        Language::RUST => file_name.push_str(".rs"),
+    }
+    file_name
+}
+
+// `@c(...)` attributes go between `struct` and the name, the others on their own lines
+fn get_class_attributes(class: &Class, language: &Language) -> String {
+    let mut output = String::new();
+    for attribute in class.get_attributes_for(language) {
+        output.push_str(attribute.as_str());
+        match language {
+            Language::C => output.push(' '),
+            _ => output.push('\n'),
+        }
+    }
+    output
+}
+
+fn find_interface<'a>(objects: &'a [Class], name: &str) -> Option<&'a Class> {
+    objects
+        .iter()
+        .find(|o| o.is_interface() && o.get_name() == name)
+}
+
+// the classes `class` refers to through its interfaces and field types, each listed once
+fn find_referenced_classes<'a>(class: &Class, objects: &'a [Class]) -> Vec<&'a Class> {
+    let type_params = class.get_type_parameters();
+    let mut names = class.get_implements();
+    for field in class.fields.iter() {
+        names.extend(field.get_field_type().get_referenced_types());
+    }
+    let mut referenced: Vec<&Class> = Vec::new();
+    for name in names.iter() {
+        if type_params.contains(name) || *name == class.get_name() {
+            continue;
+        }
+        if let Some(object) = objects.iter().find(|o| &o.get_name() == name) {
+            if !referenced.iter().any(|r| r.get_name() == object.get_name()) {
+                referenced.push(object);
+            }
+        }
+    }
+    referenced
+}
+
+// Java has no free constants, so those of one package share a `Constants` holder class
+fn find_java_constants<'a>(class: &Class, objects: &'a [Class]) -> Vec<&'a Class> {
+    objects
+        .iter()
+        .filter(|o| {
+            o.is_const()
+                && o.get_namespace() == class.get_namespace()
+                && o.languages.contains(&Language::JAVA)
+        })
+        .collect()
+}
+
+fn construct_java_file(class: &Class, objects: &[Class]) -> String {
     let mut output = String::new();
+    if let Some(namespace) = class.get_namespace() {
+        output.push_str("package ");
+        output.push_str(namespace.as_str());
+        output.push_str("; \n\n");
+    }
+    if class.is_const() {
+        output.push_str("public final class Constants { \n");
+        for constant in find_java_constants(class, objects) {
+            output.push_str(constant.get_const_declaration(&Language::JAVA).as_str());
+        }
+        output.push_str("\n\tprivate Constants() {} \n} \n \n");
+        return output;
+    }
+
+    let mut imports: Vec<String> = Vec::new();
+    for referenced in find_referenced_classes(class, objects) {
+        if let Some(namespace) = referenced.get_namespace() {
+            if referenced.get_namespace() != class.get_namespace() {
+                imports.push(format!(
+                    "import {}.{}; \n",
+                    namespace,
+                    referenced.get_name()
+                ));
+            }
+        }
+    }
+    for field in class.fields.iter() {
+        for element_type in field.get_field_type().get_element_types().iter() {
+            if let Some(name) = get_java_import(element_type) {
+                let import = format!("import {}; \n", name);
+                if !imports.contains(&import) {
+                    imports.push(import);
+                }
+            }
+        }
+    }
+    // `List<T>` fields, filled through an `ArrayList` when they have a default
+    let lists: Vec<&Field> = class
+        .fields
+        .iter()
+        .filter(|f| matches!(f.get_field_type(), FieldType::LIST(_)))
+        .collect();
+    if !lists.is_empty() {
+        imports.push("import java.util.List; \n".to_string());
+    }
+    if lists.iter().any(|f| f.get_default().is_some()) && !class.is_interface() {
+        imports.push("import java.util.ArrayList; \n".to_string());
+    }
+    if class.has_constraints() && !class.is_interface() {
+        imports.push("import jakarta.validation.constraints.*; \n".to_string());
+    }
+    if class.has_wire_names() && !class.is_interface() && !class.is_union() {
+        imports.push("import com.fasterxml.jackson.annotation.JsonProperty; \n".to_string());
+    }
+    if !get_declared_tuple_lengths(class, objects).is_empty() {
+        imports.push("import com.fasterxml.jackson.annotation.JsonFormat; \n".to_string());
+    }
+    if class.is_union() {
+        imports.push("import com.fasterxml.jackson.annotation.JsonSubTypes; \n".to_string());
+        imports.push("import com.fasterxml.jackson.annotation.JsonTypeInfo; \n".to_string());
+    }
+    if !imports.is_empty() {
+        output.push_str(imports.concat().as_str());
+        output.push('\n');
+    }
+    output.push_str(construct_java_class(class, objects).as_str());
+    output
+}
+
+// tuple records `class` declares itself, nested records of implemented interfaces are
+// inherited
+fn get_declared_tuple_lengths(class: &Class, objects: &[Class]) -> Vec<usize> {
+    let mut inherited: Vec<usize> = Vec::new();
+    for interface_name in class.get_implements() {
+        if let Some(interface) = find_interface(objects, interface_name.as_str()) {
+            inherited.extend(interface.get_tuple_lengths());
+        }
+    }
+    class
+        .get_tuple_lengths()
+        .into_iter()
+        .filter(|l| !inherited.contains(l))
+        .collect()
+}
+
+// unions whose variants hold `class`
+fn find_unions<'a>(objects: &'a [Class], class: &Class) -> Vec<&'a Class> {
+    objects
+        .iter()
+        .filter(|o| o.is_union() && o.get_variant_types().contains(&class.get_name()))
+        .collect()
+}
+
+fn construct_java_class(class: &Class, objects: &[Class]) -> String {
+    let mut output = get_doc_comment(&class.get_doc(), &Language::JAVA, "");
+    if class.is_union() {
+        output.push_str(class.get_java_subtypes().as_str());
+    }
+    output.push_str(get_class_attributes(class, &Language::JAVA).as_str());
 
     match class.get_access() {
         Access::PRIVATE => output.push_str("private "),
@@ -59,34 +299,259 @@ fn construct_java_class(class: &Class) -> String {
         Access::UNDEFINED => (),
     }
 
+    if class.is_interface() {
+        output.push_str("interface ");
+        output.push_str(class.get_name().as_str());
+        output.push_str(" { \n");
+        for length in get_declared_tuple_lengths(class, objects) {
+            output.push_str(get_java_tuple_record(length).as_str());
+        }
+        output.push_str(class.get_java_interface_methods().as_str());
+        output.push_str("} \n \n");
+        return output;
+    }
+
+    if class.is_union() {
+        output.push_str("sealed interface ");
+        output.push_str(class.get_name().as_str());
+        output.push_str(" permits ");
+        output.push_str(class.get_variant_types().join(", ").as_str());
+        output.push_str(" { \n} \n \n");
+        return output;
+    }
+
+    // variants of a sealed union may not be extended further
+    let mut implements = class.get_implements();
+    let unions = find_unions(objects, class);
+    if !unions.is_empty() {
+        output.push_str("final ");
+        implements.extend(unions.iter().map(|u| u.get_name()));
+    }
     output.push_str("class ");
-    output.push_str(class.get_name().as_str());
+    output.push_str(class.get_generic_name().as_str());
+    if !implements.is_empty() {
+        output.push_str(" implements ");
+        output.push_str(implements.join(", ").as_str());
+    }
     output.push_str(" { \n");
+    for length in get_declared_tuple_lengths(class, objects) {
+        output.push_str(get_java_tuple_record(length).as_str());
+    }
     let class_fields = class.get_java_fields();
     output.push_str(class_fields.as_str());
+    for interface_name in class.get_implements() {
+        if let Some(interface) = find_interface(objects, interface_name.as_str()) {
+            output.push_str(class.get_java_getters(interface).as_str());
+        }
+    }
     output.push_str("} \n \n");
     output
 }
 
-fn construct_ts_class(class: &Class) -> String {
+// relative module path from the file of `from` to the file of `to`
+fn get_ts_import_path(from: &Class, to: &Class) -> String {
+    let from_segments = from.get_namespace_segments();
+    let to_segments = to.get_namespace_segments();
+    let common = from_segments
+        .iter()
+        .zip(to_segments.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let mut path = String::new();
+    if from_segments.len() == common {
+        path.push_str("./");
+    }
+    for _n in common..from_segments.len() {
+        path.push_str("../");
+    }
+    for segment in to_segments[common..].iter() {
+        path.push_str(segment.as_str());
+        path.push('/');
+    }
+    path.push_str(to.get_name().as_str());
+    path
+}
+
+// every module is imported by its own path, whether or not it shares the namespace
+fn get_ts_imports(class: &Class, objects: &[Class]) -> String {
     let mut output = String::new();
-    match class.get_access() {
-        Access::PRIVATE => output.push_str("private "),
-        Access::PUBLIC => output.push_str("public "),
-        Access::UNDEFINED => (),
+    let referenced = find_referenced_classes(class, objects);
+    for object in referenced.iter() {
+        output.push_str("import { ");
+        output.push_str(object.get_name().as_str());
+        output.push_str(" } from \"");
+        output.push_str(get_ts_import_path(class, object).as_str());
+        output.push_str("\"; \n");
+    }
+    if !referenced.is_empty() {
+        output.push('\n');
+    }
+    output
+}
+
+// every declaration is exported, since each lives in a module of its own that the others import
+fn construct_ts_class(class: &Class, objects: &[Class]) -> String {
+    if class.is_union() {
+        return construct_ts_union(class, objects);
+    }
+    let mut output = get_ts_imports(class, objects);
+
+    if class.is_interface() {
+        output.push_str(get_doc_comment(&class.get_doc(), &Language::TYPESCRIPT, "").as_str());
+        output.push_str(get_class_attributes(class, &Language::TYPESCRIPT).as_str());
+        output.push_str("export interface ");
+        output.push_str(class.get_name().as_str());
+        output.push_str(" { \n");
+        output.push_str(class.get_ts_fields().as_str());
+        output.push_str("} \n \n");
+        return output;
+    }
+
+    if class.is_const() {
+        output.push_str(class.get_const_declaration(&Language::TYPESCRIPT).as_str());
+        output.push_str(" \n");
+        return output;
+    }
+    if class.is_alias() {
+        output.push_str(get_doc_comment(&class.get_doc(), &Language::TYPESCRIPT, "").as_str());
+        output.push_str(get_class_attributes(class, &Language::TYPESCRIPT).as_str());
+        output.push_str("export type ");
+        output.push_str(class.get_name().as_str());
+        output.push_str(" = ");
+        output.push_str(get_ts_type(&class.get_alias_target()).as_str());
+        output.push_str("; \n \n");
+        return output;
+    }
+
+    output.push_str(get_doc_comment(&class.get_doc(), &Language::TYPESCRIPT, "").as_str());
+    output.push_str(get_class_attributes(class, &Language::TYPESCRIPT).as_str());
+    output.push_str("export class ");
+    output.push_str(class.get_generic_name().as_str());
+    if !class.get_implements().is_empty() {
+        output.push_str(" implements ");
+        output.push_str(class.get_implements().join(", ").as_str());
     }
-    output.push_str("class ");
-    output.push_str(class.get_name().as_str());
     output.push_str(" { \n");
     let class_fields = class.get_ts_fields();
     output.push_str(class_fields.as_str());
+    if class.has_constraints() {
+        output.push_str(class.get_ts_validate_method().as_str());
+    }
+    if class.has_wire_names() {
+        output.push_str(class.get_ts_to_json_method().as_str());
+    }
+    if class.has_ts_from_json(objects) {
+        output.push_str(class.get_ts_from_json_method(objects).as_str());
+    }
     output.push_str("} \n \n");
     output
 }
 
-fn construct_c_structs(class: &Class) -> String {
+// `export type Shape = | ({ kind: "circle" } & Circle) | ...;`
+fn construct_ts_union(class: &Class, objects: &[Class]) -> String {
+    let mut output = get_ts_imports(class, objects);
+    output.push_str(get_doc_comment(&class.get_doc(), &Language::TYPESCRIPT, "").as_str());
+    output.push_str(get_class_attributes(class, &Language::TYPESCRIPT).as_str());
+    output.push_str("export type ");
+    output.push_str(class.get_name().as_str());
+    output.push_str(" =");
+    output.push_str(class.get_ts_union_members().as_str());
+    output.push_str("; \n \n");
+    output
+}
+
+// every distinct set of type arguments the class is used with across `objects`
+fn find_instantiations(class: &Class, objects: &[Class]) -> Vec<Vec<ArrayType>> {
+    let mut instantiations: Vec<Vec<ArrayType>> = Vec::new();
+    for object in objects.iter() {
+        for field in object.fields.iter() {
+            if let FieldType::GENERIC(name, args) = field.get_field_type() {
+                if name == class.get_name() && !instantiations.contains(&args) {
+                    instantiations.push(args);
+                }
+            }
+        }
+    }
+    instantiations
+}
+
+fn construct_c_file(class: &Class, objects: &[Class]) -> String {
+    if class.is_union() {
+        return construct_c_union(class);
+    }
+    if class.is_const() {
+        let mut output = class.get_const_declaration(&Language::C);
+        output.push_str(" \n");
+        return output;
+    }
+    if class.is_alias() {
+        let mut output = String::new();
+        if is_c_sized_type(&class.get_alias_target()) {
+            output.push_str("#include <stdint.h> \n\n");
+        }
+        output.push_str(get_doc_comment(&class.get_doc(), &Language::C, "").as_str());
+        output.push_str("typedef ");
+        output.push_str(get_c_type(&class.get_alias_target()).as_str());
+        output.push(' ');
+        output.push_str(class.get_name().as_str());
+        output.push_str("; \n \n");
+        return output;
+    }
+    let structs: Vec<Class> = if class.is_generic() {
+        find_instantiations(class, objects)
+            .iter()
+            .map(|args| class.monomorphize(args))
+            .collect()
+    } else {
+        vec![class.clone()]
+    };
     let mut output = String::new();
+    let uses_sized_types = structs.iter().any(|s| {
+        s.fields
+            .iter()
+            .any(|f| is_c_sized_type(&f.get_field_type()))
+    });
+    if uses_sized_types {
+        output.push_str("#include <stdint.h> \n");
+    }
+    if class.has_constraints() {
+        output.push_str("#include <stdbool.h> \n#include <string.h> \n");
+        if class.has_pattern_constraints() {
+            output.push_str("#include <regex.h> \n");
+        }
+    }
+    if uses_sized_types || class.has_constraints() {
+        output.push('\n');
+    }
+    for c_struct in structs.iter() {
+        output.push_str(construct_c_structs(c_struct).as_str());
+    }
+    output
+}
+
+// a kind enum naming the active variant next to a `union` of the variant structs
+fn construct_c_union(class: &Class) -> String {
+    let mut output = class.get_c_kind_enum();
+    output.push_str(get_doc_comment(&class.get_doc(), &Language::C, "").as_str());
+    output.push_str("typedef struct ");
+    output.push_str(get_class_attributes(class, &Language::C).as_str());
+    output.push_str(class.get_name().as_str());
+    output.push_str(" { \n\tenum ");
+    output.push_str(class.get_c_kind_name().as_str());
+    output.push(' ');
+    output.push_str(class.get_discriminator().as_str());
+    output.push_str("; \n\tunion { \n");
+    output.push_str(class.get_c_union_members().as_str());
+    output.push_str("\t} value; \n}; ");
+    output.push_str(class.get_name().as_str());
+    output.push_str("\n \n");
+    output
+}
+
+fn construct_c_structs(class: &Class) -> String {
+    let mut output = get_doc_comment(&class.get_doc(), &Language::C, "");
     output.push_str("typedef struct ");
+    output.push_str(get_class_attributes(class, &Language::C).as_str());
     output.push_str(class.get_name().as_str());
     output.push_str(" { \n");
     let class_fields = class.get_c_fields();
@@ -94,19 +559,186 @@ fn construct_c_structs(class: &Class) -> String {
     output.push_str("}; ");
     output.push_str(class.get_name().as_str());
     output.push_str("\n \n");
+    if class.has_default_values() {
+        output.push_str(class.get_c_init_function().as_str());
+    }
+    if class.has_constraints() {
+        output.push_str(class.get_c_validate_function().as_str());
+    }
     output
 }
 
-fn construct_rust_structs(class: &Class) -> String {
+// serde only implements its traits for arrays of up to 32 elements, longer ones are written as
+// a sequence through `#[serde(with = "big_array")]`
+const RUST_BIG_ARRAY_MODULE: &str = "mod big_array { \n\
+\tpub fn serialize<S: serde::Serializer, T: serde::Serialize, const N: usize>( \n\
+\t\tvalue: &[T; N], \n\
+\t\tserializer: S, \n\
+\t) -> Result<S::Ok, S::Error> { \n\
+\t\tserializer.collect_seq(value.iter()) \n\
+\t} \n\
+\n\
+\tpub fn deserialize<'de, D: serde::Deserializer<'de>, T: serde::Deserialize<'de>, const N: usize>( \n\
+\t\tdeserializer: D, \n\
+\t) -> Result<[T; N], D::Error> { \n\
+\t\tlet items: Vec<T> = serde::Deserialize::deserialize(deserializer)?; \n\
+\t\tlet length = items.len(); \n\
+\t\tstd::convert::TryFrom::try_from(items) \n\
+\t\t\t.map_err(|_| serde::de::Error::invalid_length(length, &\"the declared array length\")) \n\
+\t} \n\
+} \n\n";
+
+// wraps the generated items in one nested `pub mod` per namespace segment, with the types of
+// other namespaces brought in by `use`
+fn construct_rust_file(class: &Class, objects: &[Class], user_env: &UserEnv) -> String {
+    let crates = user_env.get_rust_crates();
+    let segments = class.get_namespace_segments();
     let mut output = String::new();
+    for segment in segments.iter() {
+        output.push_str("pub mod ");
+        output.push_str(segment.as_str());
+        output.push_str(" { \n");
+    }
+    // the namespace modules are declared at the crate root
+    let mut uses: Vec<String> = Vec::new();
+    for referenced in find_referenced_classes(class, objects) {
+        if referenced.get_namespace() != class.get_namespace() {
+            let mut path = vec!["crate".to_string()];
+            path.extend(referenced.get_namespace_segments());
+            path.push(referenced.get_name());
+            uses.push(format!("use {};", path.join("::")));
+        }
+    }
+    if !uses.is_empty() {
+        output.push_str(uses.join("\n").as_str());
+        output.push_str("\n\n");
+    }
+    let mut aliases: Vec<String> = Vec::new();
+    for field in class.fields.iter() {
+        for element_type in field.get_field_type().get_element_types().iter() {
+            if let Some(alias) = get_rust_type_alias(element_type, &crates) {
+                if !aliases.contains(&alias) {
+                    aliases.push(alias);
+                }
+            }
+        }
+    }
+    if !aliases.is_empty() {
+        output.push_str(aliases.join("\n").as_str());
+        output.push_str("\n\n");
+    }
+    if class.has_rust_big_arrays() {
+        output.push_str(RUST_BIG_ARRAY_MODULE);
+    }
+    if class.is_const() {
+        output.push_str(class.get_const_declaration(&Language::RUST).as_str());
+        output.push_str(" \n");
+    } else if class.is_alias() {
+        output.push_str(construct_rust_alias(class, user_env.uses_rust_newtypes()).as_str());
+    } else {
+        output.push_str(
+            construct_rust_structs(class, objects, user_env.uses_rust_newtypes()).as_str(),
+        );
+    }
+    for _segment in segments.iter() {
+        output.push_str("} \n");
+    }
+    output
+}
+
+// a transparent newtype keeps the wire format of the aliased type
+fn construct_rust_alias(class: &Class, newtype: bool) -> String {
+    let mut output = get_doc_comment(&class.get_doc(), &Language::RUST, "");
+    let alias_target = class.get_alias_target();
+    let default_value = get_rust_default_value(&alias_target);
+    // generated structs only implement `Default`, so only newtypes of built-in types get the
+    // other std traits
+    let is_built_in = alias_target.get_referenced_types().is_empty();
+    if newtype {
+        let mut derives = vec!["serde::Serialize", "serde::Deserialize"];
+        if is_built_in {
+            derives.extend(["Clone", "Debug", "PartialEq"]);
+        }
+        if default_value == "Default::default()" {
+            derives.push("Default");
+        }
+        output.push_str(format!("#[derive({})]\n", derives.join(", ")).as_str());
+        output.push_str("#[serde(transparent)]\n");
+    }
+    output.push_str(get_class_attributes(class, &Language::RUST).as_str());
+    if let Access::PUBLIC = class.get_access() {
+        output.push_str("pub ");
+    }
+    let target = get_rust_type(&alias_target);
+    if !newtype {
+        output.push_str(format!("type {} = {}; \n \n", class.get_name(), target).as_str());
+        return output;
+    }
+    output.push_str(format!("struct {}(pub {}); \n \n", class.get_name(), target).as_str());
+    if default_value != "Default::default()" {
+        output.push_str(
+            format!(
+                "impl Default for {} {{ \n\tfn default() -> Self {{ \n\t\t{}({})\n\t}}\n}}\n \n",
+                class.get_name(),
+                class.get_name(),
+                default_value
+            )
+            .as_str(),
+        );
+    }
+    output
+}
+
+fn construct_rust_structs(class: &Class, objects: &[Class], newtypes: bool) -> String {
+    let mut output = get_doc_comment(&class.get_doc(), &Language::RUST, "");
+    // every generated type is serializable so that it can be the type of a field of another
+    if !class.is_interface() {
+        let mut derives = vec!["serde::Serialize", "serde::Deserialize"];
+        if !class.is_union() && class.has_rust_derived_default() {
+            derives.push("Default");
+        }
+        output.push_str(format!("#[derive({})]\n", derives.join(", ")).as_str());
+    }
+    if class.is_union() {
+        output.push_str(format!("#[serde(tag = \"{}\")]\n", class.get_discriminator()).as_str());
+    }
+    output.push_str(get_class_attributes(class, &Language::RUST).as_str());
     if let Access::PUBLIC = class.get_access() {
         output.push_str("pub ")
     }
+    if class.is_interface() {
+        output.push_str("trait ");
+        output.push_str(class.get_name().as_str());
+        output.push_str(" { ");
+        output.push_str(class.get_rust_trait_methods().as_str());
+        output.push_str("}\n \n ");
+        return output;
+    }
+    if class.is_union() {
+        output.push_str("enum ");
+        output.push_str(class.get_name().as_str());
+        output.push_str(" { ");
+        output.push_str(class.get_rust_enum_variants().as_str());
+        output.push_str("}\n \n ");
+        output.push_str(class.get_rust_enum_default_impl().as_str());
+        return output;
+    }
     output.push_str("struct ");
-    output.push_str(class.get_name().as_str());
+    output.push_str(class.get_generic_name().as_str());
     output.push_str(" { ");
     let class_fields = class.get_rust_fields();
     output.push_str(class_fields.as_str());
     output.push_str("}\n \n ");
+    if !class.has_rust_derived_default() {
+        output.push_str(class.get_rust_default_impl(objects, newtypes).as_str());
+    }
+    if class.has_constraints() {
+        output.push_str(class.get_rust_validate_impl(objects, newtypes).as_str());
+    }
+    for interface_name in class.get_implements() {
+        if let Some(interface) = find_interface(objects, interface_name.as_str()) {
+            output.push_str(class.get_rust_trait_impl(interface).as_str());
+        }
+    }
     output
 }
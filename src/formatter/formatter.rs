@@ -0,0 +1,406 @@
+use crate::common::{
+    create_file, handle_result_error, is_comment, is_doc_comment, open_file, read_file, tokenize,
+    write_file, MError,
+};
+use crate::parser::{check_if_brackets_align, is_annotation, Parser};
+use colored::Colorize;
+
+const INDENT: &str = "    ";
+
+// a schema as written, comments and blank lines included
+#[derive(Clone, Debug)]
+pub enum Node {
+    // `//`, `///` or `/* */` comment on a line of its own
+    Comment(String),
+    // comment after the code of the previous node, on the same line
+    Trailing(String),
+    // one or more empty lines
+    Blank,
+    // `import`, `package` or `targets`
+    Directive(String),
+    // a `[ rs, ts ]` header or an annotation, printed right above what it belongs to
+    Attached(String),
+    // an alias or constant declaration
+    Declaration(String),
+    Field(String),
+    // `pub User implements Named` and its members
+    Block(String, Vec<Node>),
+}
+
+// `class_mapper fmt [--check] user.txt ...` rewrites each schema in the canonical layout, or
+// with `--check` only lists the ones that differ from it
+pub fn format_files(args: &[String]) {
+    let mut check = false;
+    let mut files: Vec<String> = Vec::new();
+    for arg in args.iter() {
+        if arg.as_str() == "--check" {
+            check = true;
+        } else if arg.starts_with("--") {
+            let mut message = "Unknown fmt option ".to_string();
+            message.push_str(arg.as_str());
+            handle_result_error(MError::UserEnvError(message));
+        } else {
+            files.push(arg.clone());
+        }
+    }
+    if files.is_empty() {
+        let message = "Expected at least one schema file after `fmt`.".to_string();
+        handle_result_error(MError::UserEnvError(message));
+    }
+
+    let mut unformatted: Vec<String> = Vec::new();
+    for file in files.iter() {
+        let content = read_file(&mut open_file(file.as_str()), file.as_str());
+        let formatted = format_schema(content.as_str());
+        if formatted == content {
+            continue;
+        }
+        if check {
+            println!("{} {:?}", "Would reformat".yellow(), file);
+            unformatted.push(file.clone());
+        } else {
+            write_file(&mut create_file(file.as_str()), formatted.as_str());
+            println!("{} {:?}", "Formatted".green(), file);
+        }
+    }
+    if !unformatted.is_empty() {
+        println!(
+            "{} schema file(s) are not formatted, run `class_mapper fmt` on them",
+            unformatted.len()
+        );
+        std::process::exit(1);
+    }
+}
+
+pub fn format_schema(content: &str) -> String {
+    // only schemas the parser accepts are formatted, so no token is ever misread
+    let tokens: Vec<String> = tokenize(content, false)
+        .into_iter()
+        .map(|(t, _l)| t)
+        .collect();
+    check_if_brackets_align(&tokens);
+    Parser::new().parse(&tokens);
+
+    let nodes = build_nodes(&tokenize(content, true));
+    let mut output = String::new();
+    print_nodes(&nodes, "", &mut output);
+    output
+}
+
+struct Cursor<'a> {
+    tokens: &'a [(String, usize)],
+    index: usize,
+    // line the previously taken token ends on
+    last_line: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn peek(&self) -> Option<&'a str> {
+        self.tokens.get(self.index).map(|(t, _l)| t.as_str())
+    }
+
+    fn take(&mut self) -> String {
+        let (token, line) = self.tokens[self.index].clone();
+        self.last_line = line + token.matches('\n').count();
+        self.index += 1;
+        token
+    }
+
+    // a comment on its own line or after code, along with the blank line before it
+    fn take_comment(&mut self, nodes: &mut Vec<Node>) -> bool {
+        let is_trailing = match self.tokens.get(self.index) {
+            Some((token, line)) if is_comment(token) => *line == self.last_line,
+            _ => return false,
+        };
+        if is_trailing {
+            let comment = self.take();
+            nodes.push(Node::Trailing(comment));
+        } else {
+            self.take_blank(nodes);
+            let comment = format_token(self.take().as_str());
+            nodes.push(Node::Comment(comment));
+        }
+        true
+    }
+
+    fn take_blank(&mut self, nodes: &mut Vec<Node>) {
+        if let Some((_token, line)) = self.tokens.get(self.index) {
+            if self.last_line > 0 && *line > self.last_line + 1 {
+                nodes.push(Node::Blank);
+            }
+        }
+    }
+
+    // the next code token, with any comment in between kept as a trailing comment
+    fn take_code(&mut self, comments: &mut Vec<Node>) -> String {
+        while self.peek().is_some_and(is_comment) {
+            let comment = self.take();
+            comments.push(Node::Trailing(comment));
+        }
+        match self.peek() {
+            Some(_) => format_token(self.take().as_str()),
+            None => String::new(),
+        }
+    }
+
+    // `[ rs, ts ]` with the languages as written
+    fn take_header(&mut self, comments: &mut Vec<Node>) -> String {
+        self.take();
+        let mut languages: Vec<String> = Vec::new();
+        loop {
+            let token = self.take_code(comments);
+            if token == "]" || token.is_empty() {
+                break;
+            }
+            languages.extend(
+                token
+                    .split(',')
+                    .map(|l| l.trim())
+                    .filter(|l| !l.is_empty())
+                    .map(|l| l.to_string()),
+            );
+        }
+        format!("[ {} ]", languages.join(", "))
+    }
+}
+
+fn build_nodes(tokens: &[(String, usize)]) -> Vec<Node> {
+    let mut cursor = Cursor {
+        tokens,
+        index: 0,
+        last_line: 0,
+    };
+    let mut nodes = Vec::new();
+    while let Some(token) = cursor.peek() {
+        if cursor.take_comment(&mut nodes) {
+            continue;
+        }
+        cursor.take_blank(&mut nodes);
+        let mut comments = Vec::new();
+        let node = match token {
+            "import" => {
+                cursor.take();
+                let path = cursor.take_code(&mut comments);
+                Node::Directive(format!("import {}", path.trim_end_matches(';')))
+            }
+            "package" | "namespace" => {
+                let keyword = cursor.take();
+                let name = cursor.take_code(&mut comments);
+                Node::Directive(format!("{} {};", keyword, name.trim_end_matches(';')))
+            }
+            "targets" => {
+                cursor.take();
+                Node::Directive(format!("targets {}", cursor.take_header(&mut comments)))
+            }
+            "[" => Node::Attached(cursor.take_header(&mut comments)),
+            t if is_annotation(t) => Node::Attached(format_token(cursor.take().as_str())),
+            _ => build_declaration(&mut cursor, &mut comments),
+        };
+        nodes.push(node);
+        nodes.extend(comments);
+    }
+    nodes
+}
+
+// a class, interface or union with its members, or an alias or constant
+fn build_declaration(cursor: &mut Cursor, comments: &mut Vec<Node>) -> Node {
+    let mut head: Vec<String> = Vec::new();
+    loop {
+        let token = cursor.take_code(comments);
+        match token.as_str() {
+            "" | "{" => break,
+            "type" | "const" => {
+                // `type Name = Type` and `const NAME: Type = value`
+                let length = if token == "type" { 3 } else { 4 };
+                head.push(token);
+                for _n in 0..length {
+                    head.push(cursor.take_code(comments));
+                }
+                let mut declaration = head.join(" ");
+                if !declaration.ends_with(';') {
+                    declaration.push(';');
+                }
+                return Node::Declaration(declaration);
+            }
+            _ => head.push(token),
+        }
+    }
+
+    let mut members = Vec::new();
+    while let Some(token) = cursor.peek() {
+        if cursor.take_comment(&mut members) {
+            continue;
+        }
+        cursor.take_blank(&mut members);
+        if token == "}" {
+            cursor.take();
+            break;
+        }
+        if is_annotation(token) {
+            members.push(Node::Attached(format_token(cursor.take().as_str())));
+            continue;
+        }
+        // `[pub] name: Type [= value][,]`
+        let mut field_comments = Vec::new();
+        let mut field = vec![cursor.take_code(&mut field_comments)];
+        if field[0] == "pub" || field[0] == "priv" {
+            field.push(cursor.take_code(&mut field_comments));
+        }
+        let field_type = cursor.take_code(&mut field_comments);
+        let has_default = !field_type.ends_with(',') && cursor.peek() == Some("=");
+        field.push(field_type);
+        if has_default {
+            field.push(cursor.take_code(&mut field_comments));
+            field.push(cursor.take_code(&mut field_comments));
+        }
+        members.push(Node::Field(field.join(" ")));
+        members.extend(field_comments);
+    }
+    Node::Block(head.join(" "), members)
+}
+
+// canonical spacing inside a token, e.g. `@length(min = 1, max = 64)` and `[int; 16]`
+fn format_token(token: &str) -> String {
+    if is_doc_comment(token) {
+        return match token[3..].trim() {
+            "" => "///".to_string(),
+            doc => format!("/// {}", doc),
+        };
+    }
+    if is_comment(token) {
+        return token.to_string();
+    }
+    let chars: Vec<char> = token.chars().collect();
+    let mut output = String::new();
+    let mut quote: Option<char> = None;
+    let mut depth = 0;
+    let mut escaped = false;
+    for (i, c) in chars.iter().enumerate() {
+        output.push(*c);
+        if let Some(q) = quote {
+            if !escaped && *c == q {
+                quote = None;
+            }
+            escaped = !escaped && *c == '\\';
+            continue;
+        }
+        match c {
+            '"' | '\'' => quote = Some(*c),
+            '(' | '[' | '<' => depth += 1,
+            ')' | ']' | '>' => depth -= 1,
+            ',' | ';' if i + 1 < chars.len() => output.push(' '),
+            '=' if depth > 0 => {
+                output.pop();
+                output.push_str(" = ");
+            }
+            _ => (),
+        }
+    }
+    output
+}
+
+fn print_nodes(nodes: &[Node], indent: &str, output: &mut String) {
+    let mut previous: Option<&Node> = None;
+    let mut blank = false;
+    for node in nodes.iter() {
+        if let Node::Trailing(comment) = node {
+            output.pop();
+            output.push(' ');
+            output.push_str(comment.as_str());
+            output.push('\n');
+            continue;
+        }
+        if let Node::Blank = node {
+            blank = true;
+            continue;
+        }
+        if let Some(previous) = previous {
+            if !matches!(previous, Node::Attached(_)) && (blank || needs_blank(previous, node)) {
+                output.push('\n');
+            }
+        }
+        blank = false;
+        previous = Some(node);
+        output.push_str(indent);
+        match node {
+            Node::Comment(text)
+            | Node::Directive(text)
+            | Node::Attached(text)
+            | Node::Declaration(text)
+            | Node::Field(text) => output.push_str(text.as_str()),
+            Node::Block(head, members) => {
+                output.push_str(head.as_str());
+                output.push_str(" {\n");
+                let member_indent = format!("{}{}", indent, INDENT);
+                print_nodes(members, member_indent.as_str(), output);
+                output.push_str(indent);
+                output.push('}');
+            }
+            Node::Trailing(_) | Node::Blank => (),
+        }
+        output.push('\n');
+    }
+}
+
+// declarations are always set apart, as is each group of directives
+fn needs_blank(previous: &Node, node: &Node) -> bool {
+    match (previous, node) {
+        (Node::Block(_, _) | Node::Declaration(_), _) => true,
+        (Node::Directive(a), Node::Directive(b)) => a.split(' ').next() != b.split(' ').next(),
+        (Node::Directive(_), _) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = "import \"common.txt\"\npackage com.acme.users;\ntargets [ rs, ts ]\n\n\n\
+// users of the service\n[ rs, ts, java ]   @deprecated(\"use Person\")\npub User implements Named {\n\
+/// login name\n@length(1,   64) pub name: String = \"a  b\", // trailing\n\n\n  pub age: int = 5,\n\
+pub tags: List<String> = [\"x\",\"y\"]\n}\n/* block\n   comment */\npub type UserId = String\n\
+pub const MAX_AGE: int = 150\nEmpty {\n}\n";
+
+    #[test]
+    fn formatting_is_idempotent() {
+        let formatted = format_schema(SCHEMA);
+        assert_ne!(formatted, SCHEMA);
+        assert_eq!(format_schema(formatted.as_str()), formatted);
+    }
+
+    #[test]
+    fn formatting_keeps_comments_and_literals() {
+        let formatted = format_schema(SCHEMA);
+        for text in [
+            "// users of the service",
+            "/// login name",
+            "// trailing",
+            "/* block\n   comment */",
+            "\"a  b\"",
+            "@deprecated(\"use Person\")",
+        ] {
+            assert!(
+                formatted.contains(text),
+                "{:?} missing from\n{}",
+                text,
+                formatted
+            );
+        }
+    }
+
+    #[test]
+    fn formatting_keeps_the_tokens() {
+        // declarations are terminated with `;` once formatted
+        let get_tokens = |content: &str| -> Vec<String> {
+            tokenize(content, true)
+                .into_iter()
+                .map(|(t, _l)| t.trim_end_matches(';').to_string())
+                .collect()
+        };
+        assert_eq!(
+            get_tokens(format_schema(SCHEMA).as_str()),
+            get_tokens(SCHEMA)
+        );
+    }
+}
@@ -1,72 +1,81 @@
+#![allow(clippy::upper_case_acronyms, clippy::module_inception)]
+
 mod common;
 mod deparser;
+mod formatter;
 mod models;
 mod objects;
 mod parser;
 mod user_env;
 
 use crate::deparser::DeParser;
-use crate::parser::Parser;
 use std::env;
 use std::thread;
 use std::time::Instant;
 
 use colored::Colorize;
-use common::get_file_buffer;
 use common::handle_result_error;
-use common::open_file;
 use common::MError;
-use parser::check_if_brackets_align;
-use std::sync::{Arc, Mutex};
-
-fn ensure_input_is_text_file(file: &str) -> bool {
-    let file_length = file.len();
-    let file_extension_start_index = file_length - 4;
-    file[file_extension_start_index..] == *".txt"
-}
+use formatter::format_files;
+use parser::check_duplicate_names;
+use parser::check_generic_arguments;
+use parser::check_import_cycles;
+use parser::check_interface_implementations;
+use parser::check_union_variants;
+use parser::check_unresolved_types;
+use parser::lint_schema_files;
+use parser::load_schema_files;
+use parser::mark_recursive_fields;
+use parser::resolve_aliases;
+use parser::resolve_constants;
+use parser::SymbolTable;
+use std::sync::Arc;
+use user_env::UserEnv;
 
 fn main() {
     let start_time = Instant::now();
     let args: Vec<String> = env::args().collect();
+    if args.get(1).map(|a| a.as_str()) == Some("fmt") {
+        format_files(&args[2..]);
+        return;
+    }
+
+    let user_env = UserEnv::from_args(&args[1..]);
+
+    let schema_files = load_schema_files(&user_env.get_files());
+    check_import_cycles(&schema_files);
+    check_duplicate_names(&schema_files);
+
+    let mut symbol_table = SymbolTable::new(schema_files);
+    check_unresolved_types(&symbol_table);
+    lint_schema_files(&symbol_table, &user_env.get_lint_levels());
+    resolve_aliases(&mut symbol_table);
+    resolve_constants(&mut symbol_table);
+    mark_recursive_fields(&mut symbol_table);
+    let all_objects = symbol_table.get_objects();
+    check_interface_implementations(&all_objects);
+    check_generic_arguments(&all_objects);
+    check_union_variants(&all_objects);
 
     let mut children = vec![];
-    let args_mutex = Mutex::new(args.clone());
-    let args_arc = Arc::new(args_mutex);
+    let symbol_table_arc = Arc::new(symbol_table);
+    let files = symbol_table_arc.get_files();
 
-    for n in 1..args.len() {
-        let args = Arc::clone(&args_arc);
+    for n in 0..files.len() {
+        let symbol_table = Arc::clone(&symbol_table_arc);
+        let user_env = user_env.clone();
         children.push(thread::spawn(move || {
-            let args = match args.lock() {
-                Ok(e) => e,
-                Err(_er) => {
-                    let message =
-                        "An error occurred obtaining command line arguments in the thread."
-                            .to_string();
-                    handle_result_error(MError::GenError(message));
-                    panic!()
-                }
-            };
-            println!("{}{:?}", "Starting thread ".cyan(), n);
-            let input_string = match args.get(n as usize) {
-                Some(s) => s,
+            let file = match symbol_table.files.get(n) {
+                Some(f) => f,
                 None => {
-                    let message = "Error occurred obtaining input file name.".to_string();
+                    let message = "Error occurred obtaining the parsed input file.".to_string();
                     handle_result_error(MError::GenError(message));
                     panic!()
                 }
             };
-            if !ensure_input_is_text_file(input_string) {
-                let message = "Input file must have a .txt extension to be analyzed.".to_string();
-                handle_result_error(MError::GenError(message));
-                panic!()
-            }
-            let mut input_file = open_file(input_string);
-            let mut parser = Parser::new();
-            let file_content = get_file_buffer(&mut input_file, input_string.as_str());
-            check_if_brackets_align(&file_content);
-
-            parser.parse(&file_content);
-            let mut deparser = DeParser::new(parser.get_objects());
+            println!("{}{:?}", "Starting thread ".cyan(), file.get_name());
+            let mut deparser =
+                DeParser::new(file.objects.clone(), symbol_table.get_objects(), user_env);
             deparser.construct();
         }));
     }
@@ -1,6 +1,5 @@
-use std::cmp::Ordering;
-
-#[derive(Clone, Debug, PartialEq, PartialOrd, Eq)]
+// the derived order is the declaration order, used to sort and deduplicate target lists
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Language {
     JAVA,
     TYPESCRIPT,
@@ -8,33 +7,168 @@ pub enum Language {
     RUST,
 }
 
-impl Ord for Language {
-    fn cmp(&self, other: &Self) -> Ordering {
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "gen", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut",
+    "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "try",
+    "type", "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final", "macro",
+    "override", "priv", "typeof", "unsized", "virtual", "yield",
+];
+
+const C_KEYWORDS: &[&str] = &[
+    "auto",
+    "bool",
+    "break",
+    "case",
+    "char",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extern",
+    "false",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "inline",
+    "int",
+    "long",
+    "register",
+    "restrict",
+    "return",
+    "short",
+    "signed",
+    "sizeof",
+    "static",
+    "struct",
+    "switch",
+    "true",
+    "typedef",
+    "union",
+    "unsigned",
+    "void",
+    "volatile",
+    "while",
+    "_Bool",
+    "_Complex",
+    "_Imaginary",
+];
+
+const JAVA_KEYWORDS: &[&str] = &[
+    "abstract",
+    "assert",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "implements",
+    "import",
+    "instanceof",
+    "int",
+    "interface",
+    "long",
+    "native",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "record",
+    "return",
+    "short",
+    "static",
+    "strictfp",
+    "super",
+    "switch",
+    "synchronized",
+    "this",
+    "throw",
+    "throws",
+    "transient",
+    "true",
+    "try",
+    "var",
+    "void",
+    "volatile",
+    "while",
+    "yield",
+];
+
+impl Language {
+    // TypeScript allows reserved words as property names so it has no table
+    pub fn is_keyword(&self, name: &str) -> bool {
+        match self {
+            Language::RUST => RUST_KEYWORDS.contains(&name),
+            Language::C => C_KEYWORDS.contains(&name),
+            Language::JAVA => JAVA_KEYWORDS.contains(&name),
+            Language::TYPESCRIPT => false,
+        }
+    }
+
+    // `type` becomes `r#type` in Rust, `int_` in C and `_class` in Java
+    pub fn escape_identifier(&self, name: &str) -> String {
+        if !self.is_keyword(name) {
+            return name.to_string();
+        }
         match self {
-            Language::JAVA => match other {
-                Language::JAVA => Ordering::Equal,
-                Language::TYPESCRIPT => Ordering::Greater,
-                Language::C => Ordering::Less,
-                Language::RUST => Ordering::Greater,
-            },
-            Language::TYPESCRIPT => match other {
-                Language::JAVA => Ordering::Less,
-                Language::TYPESCRIPT => Ordering::Equal,
-                Language::C => Ordering::Less,
-                Language::RUST => Ordering::Less,
-            },
-            Language::C => match other {
-                Language::JAVA => Ordering::Less,
-                Language::TYPESCRIPT => Ordering::Less,
-                Language::C => Ordering::Equal,
-                Language::RUST => Ordering::Less,
-            },
-            Language::RUST => match other {
-                Language::JAVA => Ordering::Greater,
-                Language::TYPESCRIPT => Ordering::Less,
-                Language::C => Ordering::Greater,
-                Language::RUST => Ordering::Equal,
-            },
+            // these cannot be raw identifiers
+            Language::RUST if matches!(name, "crate" | "self" | "Self" | "super") => {
+                format!("{}_", name)
+            }
+            Language::RUST => format!("r#{}", name),
+            Language::JAVA => format!("_{}", name),
+            _ => format!("{}_", name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_keywords_of_each_language() {
+        assert_eq!(Language::RUST.escape_identifier("type"), "r#type");
+        assert_eq!(Language::RUST.escape_identifier("self"), "self_");
+        assert_eq!(Language::JAVA.escape_identifier("class"), "_class");
+        assert_eq!(Language::C.escape_identifier("static"), "static_");
+        assert_eq!(Language::TYPESCRIPT.escape_identifier("class"), "class");
+    }
+
+    #[test]
+    fn leaves_other_names_alone() {
+        for language in [
+            Language::JAVA,
+            Language::TYPESCRIPT,
+            Language::C,
+            Language::RUST,
+        ] {
+            assert_eq!(language.escape_identifier("name"), "name");
         }
+        // keywords are case-sensitive
+        assert_eq!(Language::RUST.escape_identifier("Type"), "Type");
     }
 }
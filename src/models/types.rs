@@ -1,26 +1,48 @@
 #[allow(dead_code)]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Access {
     PRIVATE,
     PUBLIC,
     UNDEFINED,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 #[allow(dead_code)]
 pub enum FieldType {
     INTEGER,
     STRING,
     BOOL,
     ARRAY(ArrayType),
+    // `[int; 16]`
+    FIXEDARRAY(ArrayType, usize),
     CHAR,
     DOUBLE,
+    // explicit widths, `INTEGER` and `DOUBLE` are the 32 bit integer and 64 bit float
+    I8,
+    I16,
+    I64,
+    U8,
+    U16,
+    U32,
+    U64,
+    FLOAT,
+    DATETIME,
+    DATE,
+    DURATION,
+    UUID,
+    DECIMAL,
+    BYTES,
     CUSTOM(String),
+    // a `type UserId = String` declaration, resolved from `CUSTOM` after parsing
+    ALIAS(String),
     LIST(ArrayType),
+    GENERIC(String, Vec<ArrayType>),
+    // `(double, double)`
+    TUPLE(Vec<ArrayType>),
     UNDEFINED,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 #[allow(dead_code)]
 pub enum ArrayType {
     INTEGER,
@@ -28,5 +50,316 @@ pub enum ArrayType {
     BOOL,
     CHAR,
     DOUBLE,
+    I8,
+    I16,
+    I64,
+    U8,
+    U16,
+    U32,
+    U64,
+    FLOAT,
+    DATETIME,
+    DATE,
+    DURATION,
+    UUID,
+    DECIMAL,
+    BYTES,
     CUSTOM(String),
+    ALIAS(String),
+}
+
+impl FieldType {
+    // every custom type name the field refers to, including generic arguments
+    pub fn get_referenced_types(&self) -> Vec<String> {
+        let custom_name = |array_type: &ArrayType| match array_type {
+            ArrayType::CUSTOM(name) | ArrayType::ALIAS(name) => Some(name.clone()),
+            _ => None,
+        };
+        match self {
+            FieldType::CUSTOM(name) | FieldType::ALIAS(name) => vec![name.clone()],
+            FieldType::ARRAY(array_type)
+            | FieldType::FIXEDARRAY(array_type, _)
+            | FieldType::LIST(array_type) => custom_name(array_type).into_iter().collect(),
+            FieldType::GENERIC(name, args) => {
+                let mut names = vec![name.clone()];
+                names.extend(args.iter().filter_map(custom_name));
+                names
+            }
+            FieldType::TUPLE(items) => items.iter().filter_map(custom_name).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    // copy of the type with every custom type name passed through `rename`
+    pub fn rename_types(&self, rename: &dyn Fn(&str) -> String) -> FieldType {
+        match self {
+            FieldType::CUSTOM(name) => FieldType::CUSTOM(rename(name)),
+            FieldType::ALIAS(name) => FieldType::ALIAS(rename(name)),
+            FieldType::ARRAY(array_type) => FieldType::ARRAY(array_type.rename_types(rename)),
+            FieldType::FIXEDARRAY(array_type, length) => {
+                FieldType::FIXEDARRAY(array_type.rename_types(rename), *length)
+            }
+            FieldType::LIST(array_type) => FieldType::LIST(array_type.rename_types(rename)),
+            FieldType::GENERIC(name, args) => FieldType::GENERIC(
+                rename(name),
+                args.iter().map(|a| a.rename_types(rename)).collect(),
+            ),
+            FieldType::TUPLE(items) => {
+                FieldType::TUPLE(items.iter().map(|i| i.rename_types(rename)).collect())
+            }
+            _ => self.clone(),
+        }
+    }
+
+    // scalar types can also be array elements and generic arguments
+    pub fn to_array_type(&self) -> Option<ArrayType> {
+        let array_type = match self {
+            FieldType::INTEGER => ArrayType::INTEGER,
+            FieldType::STRING => ArrayType::STRING,
+            FieldType::BOOL => ArrayType::BOOL,
+            FieldType::CHAR => ArrayType::CHAR,
+            FieldType::DOUBLE => ArrayType::DOUBLE,
+            FieldType::I8 => ArrayType::I8,
+            FieldType::I16 => ArrayType::I16,
+            FieldType::I64 => ArrayType::I64,
+            FieldType::U8 => ArrayType::U8,
+            FieldType::U16 => ArrayType::U16,
+            FieldType::U32 => ArrayType::U32,
+            FieldType::U64 => ArrayType::U64,
+            FieldType::FLOAT => ArrayType::FLOAT,
+            FieldType::DATETIME => ArrayType::DATETIME,
+            FieldType::DATE => ArrayType::DATE,
+            FieldType::DURATION => ArrayType::DURATION,
+            FieldType::UUID => ArrayType::UUID,
+            FieldType::DECIMAL => ArrayType::DECIMAL,
+            FieldType::BYTES => ArrayType::BYTES,
+            FieldType::CUSTOM(name) => ArrayType::CUSTOM(name.clone()),
+            FieldType::ALIAS(name) => ArrayType::ALIAS(name.clone()),
+            _ => return None,
+        };
+        Some(array_type)
+    }
+
+    // copy of the type with `map` applied to the type itself when it is scalar, or to the
+    // element and argument types of arrays, lists, generics and tuples
+    pub fn map_elements(&self, map: &dyn Fn(&ArrayType) -> ArrayType) -> FieldType {
+        match self {
+            FieldType::ARRAY(array_type) => FieldType::ARRAY(map(array_type)),
+            FieldType::FIXEDARRAY(array_type, length) => {
+                FieldType::FIXEDARRAY(map(array_type), *length)
+            }
+            FieldType::LIST(array_type) => FieldType::LIST(map(array_type)),
+            FieldType::GENERIC(name, args) => {
+                FieldType::GENERIC(name.clone(), args.iter().map(map).collect())
+            }
+            FieldType::TUPLE(items) => FieldType::TUPLE(items.iter().map(map).collect()),
+            _ => match self.to_array_type() {
+                Some(array_type) => map(&array_type).to_field_type(),
+                None => self.clone(),
+            },
+        }
+    }
+
+    // the type itself, or the element and argument types of arrays, lists, generics and tuples
+    pub fn get_element_types(&self) -> Vec<FieldType> {
+        match self {
+            FieldType::ARRAY(array_type)
+            | FieldType::FIXEDARRAY(array_type, _)
+            | FieldType::LIST(array_type) => vec![array_type.to_field_type()],
+            FieldType::GENERIC(_, args) | FieldType::TUPLE(args) => {
+                args.iter().map(|a| a.to_field_type()).collect()
+            }
+            _ => vec![self.clone()],
+        }
+    }
+
+    // inclusive range of values an integer type can hold
+    pub fn get_integer_range(&self) -> Option<(i128, i128)> {
+        match self {
+            FieldType::I8 => Some((i8::MIN as i128, i8::MAX as i128)),
+            FieldType::I16 => Some((i16::MIN as i128, i16::MAX as i128)),
+            FieldType::INTEGER => Some((i32::MIN as i128, i32::MAX as i128)),
+            FieldType::I64 => Some((i64::MIN as i128, i64::MAX as i128)),
+            FieldType::U8 => Some((0, u8::MAX as i128)),
+            FieldType::U16 => Some((0, u16::MAX as i128)),
+            FieldType::U32 => Some((0, u32::MAX as i128)),
+            FieldType::U64 => Some((0, u64::MAX as i128)),
+            _ => None,
+        }
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        self.get_integer_range().is_some() || matches!(self, FieldType::DOUBLE | FieldType::FLOAT)
+    }
+}
+
+impl ArrayType {
+    pub fn rename_types(&self, rename: &dyn Fn(&str) -> String) -> ArrayType {
+        match self {
+            ArrayType::CUSTOM(name) => ArrayType::CUSTOM(rename(name)),
+            ArrayType::ALIAS(name) => ArrayType::ALIAS(rename(name)),
+            _ => self.clone(),
+        }
+    }
+
+    pub fn to_field_type(&self) -> FieldType {
+        match self {
+            ArrayType::INTEGER => FieldType::INTEGER,
+            ArrayType::STRING => FieldType::STRING,
+            ArrayType::BOOL => FieldType::BOOL,
+            ArrayType::CHAR => FieldType::CHAR,
+            ArrayType::DOUBLE => FieldType::DOUBLE,
+            ArrayType::I8 => FieldType::I8,
+            ArrayType::I16 => FieldType::I16,
+            ArrayType::I64 => FieldType::I64,
+            ArrayType::U8 => FieldType::U8,
+            ArrayType::U16 => FieldType::U16,
+            ArrayType::U32 => FieldType::U32,
+            ArrayType::U64 => FieldType::U64,
+            ArrayType::FLOAT => FieldType::FLOAT,
+            ArrayType::DATETIME => FieldType::DATETIME,
+            ArrayType::DATE => FieldType::DATE,
+            ArrayType::DURATION => FieldType::DURATION,
+            ArrayType::UUID => FieldType::UUID,
+            ArrayType::DECIMAL => FieldType::DECIMAL,
+            ArrayType::BYTES => FieldType::BYTES,
+            ArrayType::CUSTOM(name) => FieldType::CUSTOM(name.clone()),
+            ArrayType::ALIAS(name) => FieldType::ALIAS(name.clone()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ClassKind {
+    CLASS,
+    INTERFACE,
+    UNION,
+    ALIAS,
+    CONST,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Literal {
+    INTEGER(i64),
+    DOUBLE(f64),
+    STRING(String),
+    CHAR(String),
+    BOOL(bool),
+    ARRAY(Vec<Literal>),
+    // name of a schema constant, replaced by its value once all files are loaded
+    CONSTANT(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Constraint {
+    MIN(Literal),
+    MAX(Literal),
+    LENGTH(Option<Literal>, Option<Literal>),
+    PATTERN(String),
+    NONEMPTY,
+}
+
+// how a lint rule is reported, `ERROR` fails the run
+#[derive(Clone, Debug, PartialEq)]
+pub enum Severity {
+    ALLOW,
+    WARN,
+    ERROR,
+}
+
+// naming convention applied to field names of a target language
+#[derive(Clone, Debug, PartialEq)]
+pub enum Case {
+    SNAKE,
+    CAMEL,
+    PASCAL,
+    PRESERVE,
+}
+
+impl Case {
+    pub fn convert(&self, name: &str) -> String {
+        if *self == Case::PRESERVE {
+            return name.to_string();
+        }
+        let words = split_words(name);
+        let mut output = String::new();
+        for (i, word) in words.iter().enumerate() {
+            match self {
+                Case::SNAKE => {
+                    if i > 0 {
+                        output.push('_');
+                    }
+                    output.push_str(word.to_lowercase().as_str());
+                }
+                Case::CAMEL if i == 0 => output.push_str(word.to_lowercase().as_str()),
+                _ => {
+                    let mut chars = word.chars();
+                    if let Some(first) = chars.next() {
+                        output.extend(first.to_uppercase());
+                        output.push_str(chars.as_str().to_lowercase().as_str());
+                    }
+                }
+            }
+        }
+        // keeps leading underscores, which mark unused fields in some languages
+        let prefix_len = name.len() - name.trim_start_matches('_').len();
+        let mut prefixed = name[..prefix_len].to_string();
+        prefixed.push_str(output.as_str());
+        prefixed
+    }
+}
+
+// `isUsed`, `is_used` and `HTTPServer` are split into `is`/`used` and `HTTP`/`Server`
+fn split_words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words: Vec<String> = Vec::new();
+    let mut word = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if *c == '_' || *c == '-' {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+        if c.is_uppercase() && !word.is_empty() {
+            let previous = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if !previous.is_uppercase() || next_is_lower {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+        word.push(*c);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_between_cases() {
+        for name in ["isUsed", "is_used", "IsUsed"] {
+            assert_eq!(Case::SNAKE.convert(name), "is_used");
+            assert_eq!(Case::CAMEL.convert(name), "isUsed");
+            assert_eq!(Case::PASCAL.convert(name), "IsUsed");
+            assert_eq!(Case::PRESERVE.convert(name), name);
+        }
+    }
+
+    #[test]
+    fn keeps_acronyms_together() {
+        assert_eq!(Case::SNAKE.convert("HTTPServer"), "http_server");
+        assert_eq!(Case::CAMEL.convert("userID"), "userId");
+        assert_eq!(Case::PASCAL.convert("parse_url"), "ParseUrl");
+    }
+
+    #[test]
+    fn keeps_leading_underscores() {
+        assert_eq!(Case::SNAKE.convert("_unusedField"), "_unused_field");
+        assert_eq!(Case::CAMEL.convert("__private_value"), "__privateValue");
+    }
 }
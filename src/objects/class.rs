@@ -1,7 +1,8 @@
 use crate::common::handle_result_error;
 use crate::common::MError;
-use crate::models::{Access, ArrayType, FieldType, Language};
+use crate::models::{Access, ArrayType, Case, ClassKind, FieldType, Language, Literal};
 use crate::objects::fields::Field;
+use crate::objects::get_java_constraints;
 
 #[derive(Clone, Debug)]
 pub struct Class {
@@ -9,6 +10,20 @@ pub struct Class {
     pub fields: Vec<Field>,
     pub languages: Vec<Language>,
     pub access: Access,
+    pub kind: ClassKind,
+    pub implements: Vec<String>,
+    pub type_params: Vec<String>,
+    pub doc: Vec<String>,
+    pub namespace: Option<String>,
+    pub renames: Vec<(Language, String)>,
+    // name of the tag field of a union
+    pub discriminator: Option<String>,
+    // emitted verbatim by the matching language, e.g. `@c("__attribute__((packed))")`
+    pub attributes: Vec<(Language, String)>,
+    // `@deprecated("use Person")`, with an empty note when none is given
+    pub deprecated: Option<String>,
+    // line of the declaration in its schema file
+    pub line: usize,
 }
 
 impl Class {
@@ -18,9 +33,82 @@ impl Class {
             fields: Vec::new(),
             languages: languages.to_owned(),
             access,
+            kind: ClassKind::CLASS,
+            implements: Vec::new(),
+            type_params: Vec::new(),
+            doc: Vec::new(),
+            namespace: None,
+            renames: Vec::new(),
+            discriminator: None,
+            attributes: Vec::new(),
+            deprecated: None,
+            line: 0,
         }
     }
 
+    pub fn set_line(&mut self, line: usize) {
+        self.line = line;
+    }
+
+    pub fn add_rename(&mut self, language: Language, name: &str) {
+        self.renames.retain(|(l, _n)| *l != language);
+        self.renames.push((language, name.to_string()));
+    }
+
+    pub fn add_attribute(&mut self, language: Language, attribute: &str) {
+        self.attributes.push((language, attribute.to_string()));
+    }
+
+    pub fn get_attributes_for(&self, language: &Language) -> Vec<String> {
+        let mut attributes: Vec<String> = get_deprecated_attribute(&self.deprecated, language)
+            .into_iter()
+            .collect();
+        attributes.extend(get_attributes_for(&self.attributes, language));
+        attributes
+    }
+
+    pub fn set_deprecated(&mut self, note: &str) {
+        self.deprecated = Some(note.to_string());
+    }
+
+    // copy of the class with an `@deprecated` tag added to the Javadoc or TSDoc of every
+    // deprecated item, the other languages only mark them through attributes
+    pub fn with_deprecation_notes(&self, language: &Language) -> Class {
+        let mut class = self.clone();
+        if let Language::JAVA | Language::TYPESCRIPT = language {
+            add_deprecated_tag(&mut class.doc, &self.deprecated);
+            for field in class.fields.iter_mut() {
+                let deprecated = field.deprecated.clone();
+                add_deprecated_tag(&mut field.doc, &deprecated);
+            }
+        }
+        class
+    }
+
+    pub fn set_namespace(&mut self, namespace: Option<String>) {
+        self.namespace = namespace;
+    }
+
+    pub fn set_doc(&mut self, doc: &[String]) {
+        self.doc = doc.to_owned();
+    }
+
+    pub fn add_type_parameter(&mut self, name: &str) {
+        self.type_params.push(name.to_string());
+    }
+
+    pub fn set_kind(&mut self, kind: ClassKind) {
+        self.kind = kind;
+    }
+
+    pub fn set_discriminator(&mut self, discriminator: &str) {
+        self.discriminator = Some(discriminator.to_string());
+    }
+
+    pub fn add_implemented_interface(&mut self, name: &str) {
+        self.implements.push(name.to_string());
+    }
+
     pub fn add_field(&mut self, field: &Field) {
         self.fields.push(field.clone());
     }
@@ -33,6 +121,201 @@ impl Class {
         self.access.clone()
     }
 
+    pub fn get_implements(&self) -> Vec<String> {
+        self.implements.clone()
+    }
+
+    pub fn get_doc(&self) -> Vec<String> {
+        self.doc.clone()
+    }
+
+    pub fn get_namespace(&self) -> Option<String> {
+        self.namespace.clone()
+    }
+
+    pub fn get_namespace_segments(&self) -> Vec<String> {
+        match &self.namespace {
+            Some(namespace) => namespace.split('.').map(|s| s.to_string()).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    // C has no namespaces so `com.acme.User` becomes `com_acme_User`
+    pub fn get_c_name(&self) -> String {
+        let mut segments = self.get_namespace_segments();
+        segments.push(self.get_name());
+        segments.join("_")
+    }
+
+    // the `@name(...)` override for `language`, otherwise the schema name
+    pub fn get_name_for(&self, language: &Language) -> String {
+        match self.renames.iter().find(|(l, _n)| l == language) {
+            Some((_l, name)) => name.clone(),
+            None if *language == Language::C => self.get_c_name(),
+            None => self.get_name(),
+        }
+    }
+
+    // copy of the class where it, its fields and every class it references carry the names
+    // used by `language`
+    pub fn with_language_names(
+        &self,
+        language: &Language,
+        objects: &[Class],
+        case: &Case,
+    ) -> Class {
+        let rename = |name: &str| get_name_of(name, language, objects);
+        let mut class = self.clone();
+        class.name = self.get_name_for(language);
+        class.implements = self.implements.iter().map(|i| rename(i)).collect();
+        for field in class.fields.iter_mut() {
+            let name = language.escape_identifier(field.get_name_for(language, case).as_str());
+            if name != field.name {
+                field.wire_name = Some(field.name.clone());
+            }
+            field.name = name;
+            field.field_type = field.field_type.rename_types(&rename);
+        }
+        class
+    }
+
+    pub fn get_type_parameters(&self) -> Vec<String> {
+        self.type_params.clone()
+    }
+
+    pub fn is_generic(&self) -> bool {
+        !self.type_params.is_empty()
+    }
+
+    // name including its type parameters, e.g. `Page<T>`
+    pub fn get_generic_name(&self) -> String {
+        let mut name = self.get_name();
+        if self.is_generic() {
+            name.push('<');
+            name.push_str(self.type_params.join(", ").as_str());
+            name.push('>');
+        }
+        name
+    }
+
+    // concrete copy of a generic class with every type parameter replaced by `args`,
+    // used by the C backend which has no generics
+    pub fn monomorphize(&self, args: &[ArrayType]) -> Class {
+        let mut class = self.clone();
+        class.name = get_c_generic_name(self.name.as_str(), args);
+        class.type_params = Vec::new();
+        for field in class.fields.iter_mut() {
+            field.field_type = substitute_type(&field.field_type, &self.type_params, args);
+        }
+        class
+    }
+
+    pub fn is_interface(&self) -> bool {
+        self.kind == ClassKind::INTERFACE
+    }
+
+    pub fn is_union(&self) -> bool {
+        self.kind == ClassKind::UNION
+    }
+
+    pub fn is_alias(&self) -> bool {
+        self.kind == ClassKind::ALIAS
+    }
+
+    pub fn is_const(&self) -> bool {
+        self.kind == ClassKind::CONST
+    }
+
+    // constants store their type and value as a single `value` field with a default
+    pub fn get_const_value(&self) -> Option<Literal> {
+        self.fields.first().and_then(|f| f.get_default())
+    }
+
+    // `pub const MAX: i32 = 64;` and its counterparts, indented for a Java holder class
+    pub fn get_const_declaration(&self, language: &Language) -> String {
+        let field_type = self.get_alias_target();
+        let value = match self.get_const_value() {
+            Some(v) => v,
+            None => {
+                let message = format!("Constant `{}` has no value.", self.name);
+                handle_result_error(MError::ClassError(message));
+                panic!()
+            }
+        };
+        let indent = if *language == Language::JAVA {
+            "\t"
+        } else {
+            ""
+        };
+        let mut output = get_doc_comment(&self.doc, language, indent);
+        output.push_str(indent);
+        let is_public = self.access == Access::PUBLIC;
+        let literal = get_literal(&value, &field_type, language);
+        match language {
+            Language::JAVA => {
+                match self.access {
+                    Access::PUBLIC => output.push_str("public "),
+                    Access::PRIVATE => output.push_str("private "),
+                    Access::UNDEFINED => (),
+                }
+                output.push_str("static final ");
+                output.push_str(get_java_type(&field_type).as_str());
+                output.push_str(format!(" {} = {}; \n", self.name, literal).as_str());
+            }
+            Language::TYPESCRIPT => {
+                output.push_str("export const ");
+                output.push_str(
+                    format!(
+                        "{}: {} = {}; \n",
+                        self.name,
+                        get_ts_type(&field_type),
+                        literal
+                    )
+                    .as_str(),
+                );
+            }
+            Language::RUST => {
+                if is_public {
+                    output.push_str("pub ");
+                }
+                // string constants cannot allocate
+                let (rust_type, literal) = match value {
+                    Literal::STRING(v) => ("&str".to_string(), format!("\"{}\"", v)),
+                    _ => (get_rust_type(&field_type), literal),
+                };
+                output.push_str(
+                    format!("const {}: {} = {}; \n", self.name, rust_type, literal).as_str(),
+                );
+            }
+            Language::C => {
+                output.push_str(format!("#define {} {} \n", self.name, literal).as_str());
+            }
+        }
+        output
+    }
+
+    // aliases store their target as a single `value` field
+    pub fn get_alias_target(&self) -> FieldType {
+        match self.fields.first() {
+            Some(field) => field.get_field_type(),
+            None => FieldType::UNDEFINED,
+        }
+    }
+
+    // copy of the class with every alias replaced by the type it stands for, for languages
+    // without a way to declare one
+    pub fn with_inlined_aliases(&self, objects: &[Class]) -> Class {
+        let mut class = self.clone();
+        for field in class.fields.iter_mut() {
+            field.field_type = get_inlined_type(&field.field_type, objects);
+        }
+        class
+    }
+
+    pub fn get_field(&self, name: &str) -> Option<&Field> {
+        self.fields.iter().find(|f| f.name == name)
+    }
+
     pub fn get_java_fields(&self) -> String {
         let mut fields = String::new();
         for n in 0..self.fields.len() {
@@ -45,47 +328,73 @@ impl Class {
                     panic!()
                 }
             };
+            if let FieldType::FIXEDARRAY(_, length) = field.get_field_type() {
+                fields.push_str("\tpublic static final int ");
+                fields.push_str(get_length_constant_name(field).as_str());
+                fields.push_str(format!(" = {}; \n", length).as_str());
+            }
+            fields.push_str(get_doc_comment(&field.get_doc(), &Language::JAVA, "\t").as_str());
+            if let Some(wire_name) = field.get_wire_name() {
+                fields.push_str(format!("\t@JsonProperty(\"{}\")\n", wire_name).as_str());
+            }
+            fields.push_str(get_java_constraints(field).as_str());
+            fields.push_str(get_attribute_lines(field, &Language::JAVA).as_str());
             fields.push('\t');
             match field.get_access() {
                 Access::PUBLIC => fields.push_str("public "),
                 Access::PRIVATE => fields.push_str("private "),
                 _ => (),
             }
-            match field.get_field_type() {
-                FieldType::STRING => fields.push_str("String"),
-                FieldType::INTEGER => fields.push_str("int"),
-                FieldType::DOUBLE => fields.push_str("double"),
-                FieldType::CHAR => fields.push_str("char"),
-                FieldType::BOOL => fields.push_str("boolean"),
-                FieldType::CUSTOM(name) => fields.push_str(name.as_str()),
-                FieldType::ARRAY(ArrayType::INTEGER) => fields.push_str("int[]"),
-                FieldType::ARRAY(ArrayType::STRING) => fields.push_str("String[]"),
-                FieldType::ARRAY(ArrayType::CHAR) => fields.push_str("char[]"),
-                FieldType::ARRAY(ArrayType::DOUBLE) => fields.push_str("double[]"),
-                FieldType::ARRAY(ArrayType::BOOL) => fields.push_str("bool[]"),
-                FieldType::ARRAY(ArrayType::CUSTOM(name)) => {
-                    fields.push_str(name.as_str());
-                    fields.push_str("[]")
-                }
-                FieldType::LIST(ArrayType::INTEGER) => fields.push_str("List<Integer>"),
-                FieldType::LIST(ArrayType::STRING) => fields.push_str("List<String>"),
-                FieldType::LIST(ArrayType::CHAR) => fields.push_str("List<Character>"),
-                FieldType::LIST(ArrayType::DOUBLE) => fields.push_str("List<Double>"),
-                FieldType::LIST(ArrayType::BOOL) => fields.push_str("List<Boolean>"),
-                FieldType::LIST(ArrayType::CUSTOM(name)) => {
-                    fields.push_str("List<");
-                    fields.push_str(name.as_str());
-                    fields.push('>')
-                }
-                _ => (),
-            }
+            fields.push_str(get_java_type(&field.get_field_type()).as_str());
             fields.push(' ');
             fields.push_str(field.get_name().as_str());
+            if let Some(default) = field.get_default() {
+                fields.push_str(" = ");
+                fields.push_str(
+                    get_literal(&default, &field.get_value_type(), &Language::JAVA).as_str(),
+                );
+            } else if let FieldType::FIXEDARRAY(array_type, _) = field.get_field_type() {
+                fields.push_str(" = new ");
+                fields.push_str(get_java_type(&array_type.to_field_type()).as_str());
+                fields.push('[');
+                fields.push_str(get_length_constant_name(field).as_str());
+                fields.push(']');
+            }
             fields.push_str("; \n");
         }
         fields
     }
 
+    pub fn get_java_interface_methods(&self) -> String {
+        let mut methods = String::new();
+        for field in self.fields.iter() {
+            methods.push_str(get_doc_comment(&field.get_doc(), &Language::JAVA, "\t").as_str());
+            if let Some(attribute) = get_deprecated_attribute(&field.deprecated, &Language::JAVA) {
+                methods.push_str(format!("\t{}\n", attribute).as_str());
+            }
+            methods.push('\t');
+            methods.push_str(get_java_type(&field.get_field_type()).as_str());
+            methods.push(' ');
+            methods.push_str(get_java_getter_name(field).as_str());
+            methods.push_str("(); \n");
+        }
+        methods
+    }
+
+    pub fn get_java_getters(&self, interface: &Class) -> String {
+        let mut methods = String::new();
+        for field in interface.fields.iter() {
+            methods.push_str("\n\tpublic ");
+            methods.push_str(get_java_type(&field.get_field_type()).as_str());
+            methods.push(' ');
+            methods.push_str(get_java_getter_name(field).as_str());
+            methods.push_str("() { \n\t\treturn this.");
+            methods.push_str(field.get_name().as_str());
+            methods.push_str("; \n\t} \n");
+        }
+        methods
+    }
+
     pub fn get_ts_fields(&self) -> String {
         let mut fields = String::new();
         for n in 0..self.fields.len() {
@@ -98,48 +407,125 @@ impl Class {
                     panic!()
                 }
             };
+            if let (FieldType::FIXEDARRAY(_, length), false) =
+                (field.get_field_type(), self.is_interface())
+            {
+                fields.push_str("\tstatic readonly ");
+                fields.push_str(get_length_constant_name(field).as_str());
+                fields.push_str(format!(" = {}; \n", length).as_str());
+            }
+            fields
+                .push_str(get_doc_comment(&field.get_doc(), &Language::TYPESCRIPT, "\t").as_str());
+            fields.push_str(get_attribute_lines(field, &Language::TYPESCRIPT).as_str());
             fields.push('\t');
-            match field.get_access() {
-                Access::PUBLIC => fields.push_str("public "),
-                Access::PRIVATE => fields.push_str("private "),
-                _ => (),
+            if !self.is_interface() {
+                match field.get_access() {
+                    Access::PUBLIC => fields.push_str("public "),
+                    Access::PRIVATE => fields.push_str("private "),
+                    _ => (),
+                }
             }
             fields.push_str(field.get_name().as_str());
             fields.push(':');
             fields.push(' ');
-            match field.get_field_type() {
-                FieldType::STRING => fields.push_str("string"),
-                FieldType::INTEGER => fields.push_str("number"),
-                FieldType::DOUBLE => fields.push_str("number"),
-                FieldType::CHAR => fields.push_str("string"),
-                FieldType::BOOL => fields.push_str("boolean"),
-                FieldType::CUSTOM(name) => fields.push_str(name.as_str()),
-                FieldType::ARRAY(ArrayType::INTEGER) => fields.push_str("int[]"),
-                FieldType::ARRAY(ArrayType::STRING) => fields.push_str("String[]"),
-                FieldType::ARRAY(ArrayType::CHAR) => fields.push_str("char[]"),
-                FieldType::ARRAY(ArrayType::DOUBLE) => fields.push_str("double[]"),
-                FieldType::ARRAY(ArrayType::BOOL) => fields.push_str("bool[]"),
-                FieldType::ARRAY(ArrayType::CUSTOM(name)) => {
-                    fields.push_str(name.as_str());
-                    fields.push_str("[]")
-                }
-                FieldType::LIST(ArrayType::INTEGER) => fields.push_str("Array<number>"),
-                FieldType::LIST(ArrayType::STRING) => fields.push_str("Array<string>"),
-                FieldType::LIST(ArrayType::CHAR) => fields.push_str("Array<string>"),
-                FieldType::LIST(ArrayType::DOUBLE) => fields.push_str("Array<number>"),
-                FieldType::LIST(ArrayType::BOOL) => fields.push_str("Array<boolean>"),
-                FieldType::LIST(ArrayType::CUSTOM(name)) => {
-                    fields.push_str("Array<");
-                    fields.push_str(name.as_str());
-                    fields.push('>')
-                }
-                _ => (),
+            fields.push_str(get_ts_type(&field.get_field_type()).as_str());
+            if let Some(default) = field.get_default() {
+                fields.push_str(" = ");
+                fields.push_str(
+                    get_literal(&default, &field.get_value_type(), &Language::TYPESCRIPT).as_str(),
+                );
             }
             fields.push_str("; \n");
         }
         fields
     }
 
+    // TypeScript serializes fields under the names they are declared with, so a class with
+    // renamed fields writes the schema names back through `toJSON()`
+    pub fn get_ts_to_json_method(&self) -> String {
+        let mut names: Vec<String> = Vec::new();
+        let mut entries: Vec<String> = Vec::new();
+        for field in self.fields.iter() {
+            if let Some(wire_name) = field.get_wire_name() {
+                names.push(field.get_name());
+                entries.push(format!("\"{}\": {}", wire_name, field.get_name()));
+            }
+        }
+        let mut method = "\n\ttoJSON(): Record<string, unknown> { \n\t\tconst { ".to_string();
+        method.push_str(names.join(", ").as_str());
+        method.push_str(", ...json } = this; \n\t\treturn { ...json, ");
+        method.push_str(entries.join(", ").as_str());
+        method.push_str(" }; \n\t} \n");
+        method
+    }
+
+    // `fromJSON()` reads the schema names back and builds the classes of nested values that
+    // have a `fromJSON()` of their own
+    pub fn get_ts_from_json_method(&self, objects: &[Class]) -> String {
+        let mut bindings: Vec<String> = Vec::new();
+        let mut assignments = String::new();
+        for field in self.fields.iter() {
+            let name = field.get_name();
+            let conversion =
+                get_ts_json_conversion(&field.get_field_type(), name.as_str(), objects);
+            if field.get_wire_name().is_none() && conversion.is_none() {
+                continue;
+            }
+            let wire_name = field.get_wire_name().unwrap_or_else(|| name.clone());
+            bindings.push(format!("\"{}\": {}", wire_name, name));
+            assignments.push_str("\t\tvalue.");
+            assignments.push_str(name.as_str());
+            assignments.push_str(" = ");
+            assignments.push_str(conversion.unwrap_or_else(|| name.clone()).as_str());
+            assignments.push_str("; \n");
+        }
+        let generic_name = self.get_generic_name();
+        let mut method = "\n\tstatic fromJSON".to_string();
+        if self.is_generic() {
+            method.push_str(format!("<{}>", self.type_params.join(", ")).as_str());
+        }
+        method.push_str(format!("(json: Record<string, any>): {} {{ \n", generic_name).as_str());
+        method.push_str(
+            format!(
+                "\t\tconst {{ {}, ...fields }} = json; \n",
+                bindings.join(", ")
+            )
+            .as_str(),
+        );
+        method.push_str(
+            format!(
+                "\t\tconst value: {} = Object.assign(new {}(), fields); \n",
+                generic_name, generic_name
+            )
+            .as_str(),
+        );
+        method.push_str(assignments.as_str());
+        method.push_str("\t\treturn value; \n\t} \n");
+        method
+    }
+
+    // classes with renamed fields, or with fields holding such classes, are read through
+    // `fromJSON()`
+    pub fn has_ts_from_json(&self, objects: &[Class]) -> bool {
+        self.needs_ts_from_json(objects, &mut Vec::new())
+    }
+
+    fn needs_ts_from_json(&self, objects: &[Class], visited: &mut Vec<String>) -> bool {
+        if self.kind != ClassKind::CLASS || visited.contains(&self.name) {
+            return false;
+        }
+        visited.push(self.get_name());
+        self.has_wire_names()
+            || self.fields.iter().any(|f| {
+                get_ts_json_class(&f.get_field_type()).is_some_and(|name| {
+                    objects
+                        .iter()
+                        .find(|o| o.get_name() == name)
+                        .is_some_and(|o| o.needs_ts_from_json(objects, visited))
+                })
+            })
+    }
+
     pub fn get_c_fields(&self) -> String {
         let mut fields = String::new();
         for n in 0..self.fields.len() {
@@ -152,40 +538,34 @@ impl Class {
                     panic!()
                 }
             };
+            fields.push_str(get_doc_comment(&field.get_doc(), &Language::C, "\t").as_str());
             fields.push('\t');
             match field.get_field_type() {
-                FieldType::STRING => fields.push_str("char*"),
-                FieldType::INTEGER => fields.push_str("int"),
-                FieldType::DOUBLE => fields.push_str("double"),
-                FieldType::CHAR => fields.push_str("char"),
-                FieldType::BOOL => fields.push_str("bool"),
-                FieldType::CUSTOM(name) => {
-                    fields.push_str("struct ");
-                    fields.push_str(name.as_str());
-                }
-                FieldType::ARRAY(ArrayType::INTEGER) => fields.push_str("int*"),
-                FieldType::ARRAY(ArrayType::STRING) => fields.push_str("char*"),
-                FieldType::ARRAY(ArrayType::CHAR) => fields.push_str("char*"),
-                FieldType::ARRAY(ArrayType::DOUBLE) => fields.push_str("double*"),
-                FieldType::ARRAY(ArrayType::BOOL) => fields.push_str("bool*"),
-                FieldType::ARRAY(ArrayType::CUSTOM(name)) => {
-                    fields.push_str(name.as_str());
-                    fields.push('*')
+                FieldType::TUPLE(items) if field.is_indirect() => {
+                    fields.push_str(get_c_indirect_tuple_type(&items).as_str())
                 }
-                FieldType::LIST(ArrayType::INTEGER) => fields.push_str("int*"),
-                FieldType::LIST(ArrayType::STRING) => fields.push_str("char*"),
-                FieldType::LIST(ArrayType::CHAR) => fields.push_str("char*"),
-                FieldType::LIST(ArrayType::DOUBLE) => fields.push_str("double*"),
-                FieldType::LIST(ArrayType::BOOL) => fields.push_str("bool*"),
-                FieldType::LIST(ArrayType::CUSTOM(name)) => {
-                    fields.push_str(name.as_str());
-                    fields.push('*')
+                field_type => {
+                    fields.push_str(get_c_type(&field_type).as_str());
+                    if field.is_indirect() {
+                        fields.push('*');
+                    }
                 }
-                _ => (),
             }
             fields.push(' ');
             fields.push_str(field.get_name().as_str());
-            fields.push_str("; \n");
+            if let FieldType::FIXEDARRAY(_, length) = field.get_field_type() {
+                fields.push_str(format!("[{}]", length).as_str());
+            }
+            for attribute in field.get_attributes_for(&Language::C) {
+                fields.push(' ');
+                fields.push_str(attribute.as_str());
+            }
+            fields.push(';');
+            let element_type = field.get_field_type().get_element_types();
+            if let Some(comment) = element_type.first().and_then(get_c_type_comment) {
+                fields.push_str(format!(" /* {} */", comment).as_str());
+            }
+            fields.push_str(" \n");
         }
         fields
     }
@@ -202,46 +582,875 @@ impl Class {
                     panic!()
                 }
             };
-            fields.push_str("\n\t");
+            fields.push('\n');
+            fields.push_str(get_doc_comment(&field.get_doc(), &Language::RUST, "\t").as_str());
+            if let Some(wire_name) = field.get_wire_name() {
+                fields.push_str(format!("\t#[serde(rename = \"{}\")]\n", wire_name).as_str());
+            }
+            if is_rust_big_array(&field.get_field_type()) {
+                fields.push_str("\t#[serde(with = \"big_array\")]\n");
+            }
+            fields.push_str(get_attribute_lines(field, &Language::RUST).as_str());
+            fields.push('\t');
             if let Access::PUBLIC = field.get_access() {
                 fields.push_str("pub ")
             }
             fields.push_str(field.get_name().as_str());
             fields.push_str(": ");
-            match field.get_field_type() {
-                FieldType::STRING => fields.push_str("String"),
-                FieldType::INTEGER => fields.push_str("i32"),
-                FieldType::DOUBLE => fields.push_str("f32"),
-                FieldType::CHAR => fields.push_str("char"),
-                FieldType::BOOL => fields.push_str("bool"),
-                FieldType::CUSTOM(name) => {
-                    fields.push_str(name.as_str());
-                }
-                FieldType::ARRAY(ArrayType::INTEGER) => fields.push_str("[i32]"),
-                FieldType::ARRAY(ArrayType::STRING) => fields.push_str("[String]"),
-                FieldType::ARRAY(ArrayType::CHAR) => fields.push_str("[char]"),
-                FieldType::ARRAY(ArrayType::DOUBLE) => fields.push_str("[f64]"),
-                FieldType::ARRAY(ArrayType::BOOL) => fields.push_str("[bool]"),
-                FieldType::ARRAY(ArrayType::CUSTOM(name)) => {
-                    fields.push('[');
-                    fields.push_str(name.as_str());
-                    fields.push(']');
-                }
-                FieldType::LIST(ArrayType::INTEGER) => fields.push_str("Vec<i32>"),
-                FieldType::LIST(ArrayType::STRING) => fields.push_str("Vec<String>"),
-                FieldType::LIST(ArrayType::CHAR) => fields.push_str("Vec<char>"),
-                FieldType::LIST(ArrayType::DOUBLE) => fields.push_str("Vec<f64>"),
-                FieldType::LIST(ArrayType::BOOL) => fields.push_str("Vec<bool>"),
-                FieldType::LIST(ArrayType::CUSTOM(name)) => {
-                    fields.push_str("Vec<");
-                    fields.push_str(name.as_str());
-                    fields.push('>')
-                }
-                _ => (),
+            if field.is_indirect() {
+                fields.push_str("Option<Box<");
+                fields.push_str(get_rust_type(&field.get_field_type()).as_str());
+                fields.push_str(">>");
+            } else {
+                fields.push_str(get_rust_type(&field.get_field_type()).as_str());
             }
             fields.push(',');
         }
         fields.push('\n');
         fields
     }
+
+    // fixed-length arrays too long for serde, see `RUST_BIG_ARRAY_MODULE`
+    pub fn has_rust_big_arrays(&self) -> bool {
+        self.fields
+            .iter()
+            .any(|f| is_rust_big_array(&f.get_field_type()))
+    }
+
+    // fields emitted under a different name than the one used on the wire
+    pub fn has_wire_names(&self) -> bool {
+        self.fields.iter().any(|f| f.get_wire_name().is_some())
+    }
+
+    // distinct lengths of the tuple fields, each needing its own Java record
+    pub fn get_tuple_lengths(&self) -> Vec<usize> {
+        let mut lengths: Vec<usize> = Vec::new();
+        for field in self.fields.iter() {
+            if let FieldType::TUPLE(items) = field.get_field_type() {
+                if !lengths.contains(&items.len()) {
+                    lengths.push(items.len());
+                }
+            }
+        }
+        lengths
+    }
+
+    pub fn has_default_values(&self) -> bool {
+        self.fields.iter().any(|f| f.get_default().is_some())
+    }
+
+    // every generated struct implements `Default` so that it can be the type of a field in a
+    // class with defaults, derived when no field needs a value of its own
+    pub fn has_rust_derived_default(&self) -> bool {
+        self.fields
+            .iter()
+            .all(|f| f.get_default().is_none() && get_rust_field_default(f) == "Default::default()")
+    }
+
+    // `Default` impl using the schema defaults and `Default::default()` for the other fields
+    pub fn get_rust_default_impl(&self, objects: &[Class], newtypes: bool) -> String {
+        let mut output = String::new();
+        output.push_str("impl");
+        if self.is_generic() {
+            let bounds: Vec<String> = self
+                .type_params
+                .iter()
+                .map(|p| format!("{}: Default", p))
+                .collect();
+            output.push('<');
+            output.push_str(bounds.join(", ").as_str());
+            output.push('>');
+        }
+        output.push_str(" Default for ");
+        output.push_str(self.get_generic_name().as_str());
+        output.push_str(" { \n\tfn default() -> Self { \n\t\tSelf { ");
+        for field in self.fields.iter() {
+            output.push_str("\n\t\t\t");
+            output.push_str(field.get_name().as_str());
+            output.push_str(": ");
+            match field.get_default() {
+                Some(default) if newtypes => output.push_str(
+                    get_rust_newtype_literal(&default, &field.get_field_type(), objects).as_str(),
+                ),
+                Some(default) => output.push_str(
+                    get_literal(&default, &field.get_value_type(), &Language::RUST).as_str(),
+                ),
+                None => output.push_str(get_rust_field_default(field).as_str()),
+            }
+            output.push(',');
+        }
+        output.push_str("\n\t\t}\n\t}\n}\n \n");
+        output
+    }
+
+    // `init_<Name>()` returning a zeroed struct with the schema defaults applied
+    pub fn get_c_init_function(&self) -> String {
+        let mut output = String::new();
+        output.push_str("struct ");
+        output.push_str(self.get_name().as_str());
+        output.push_str(" init_");
+        output.push_str(self.get_name().as_str());
+        output.push_str("(void) { \n\tstruct ");
+        output.push_str(self.get_name().as_str());
+        output.push_str(" value = {0}; \n");
+        for field in self.fields.iter() {
+            let default = match field.get_default() {
+                Some(d) => d,
+                None => continue,
+            };
+            let value = match (&default, field.get_field_type()) {
+                // C arrays cannot be assigned so the elements are copied one by one
+                (Literal::ARRAY(_), FieldType::FIXEDARRAY(array_type, length)) => {
+                    let storage = format!("{}_default", field.get_name());
+                    output.push_str("\tstatic const ");
+                    output.push_str(get_c_type(&array_type.to_field_type()).as_str());
+                    output.push(' ');
+                    output.push_str(storage.as_str());
+                    output.push_str(format!("[{}] = ", length).as_str());
+                    output.push_str(
+                        get_literal(&default, &field.get_value_type(), &Language::C).as_str(),
+                    );
+                    output.push_str("; \n");
+                    output.push_str(
+                        format!(
+                            "\tfor (int i = 0; i < {}; i++) value.{}[i] = {}[i]; \n",
+                            length,
+                            field.get_name(),
+                            storage
+                        )
+                        .as_str(),
+                    );
+                    continue;
+                }
+                (Literal::ARRAY(items), _) if items.is_empty() => "NULL".to_string(),
+                (Literal::ARRAY(_), FieldType::ARRAY(array_type))
+                | (Literal::ARRAY(_), FieldType::LIST(array_type)) => {
+                    let storage = format!("{}_default", field.get_name());
+                    output.push_str("\tstatic ");
+                    output.push_str(get_c_type(&array_type.to_field_type()).as_str());
+                    output.push(' ');
+                    output.push_str(storage.as_str());
+                    output.push_str("[] = ");
+                    output.push_str(
+                        get_literal(&default, &field.get_value_type(), &Language::C).as_str(),
+                    );
+                    output.push_str("; \n");
+                    storage
+                }
+                _ => get_literal(&default, &field.get_value_type(), &Language::C),
+            };
+            output.push_str("\tvalue.");
+            output.push_str(field.get_name().as_str());
+            output.push_str(" = ");
+            output.push_str(value.as_str());
+            output.push_str("; \n");
+        }
+        output.push_str("\treturn value; \n} \n \n");
+        output
+    }
+
+    pub fn get_rust_trait_methods(&self) -> String {
+        let mut methods = String::new();
+        for field in self.fields.iter() {
+            methods.push('\n');
+            methods.push_str(get_doc_comment(&field.get_doc(), &Language::RUST, "\t").as_str());
+            if let Some(attribute) = get_deprecated_attribute(&field.deprecated, &Language::RUST) {
+                methods.push_str(format!("\t{}\n", attribute).as_str());
+            }
+            methods.push_str("\tfn ");
+            methods.push_str(field.get_name().as_str());
+            methods.push_str("(&self) -> &");
+            methods.push_str(get_rust_type(&field.get_field_type()).as_str());
+            methods.push(';');
+        }
+        methods.push('\n');
+        methods
+    }
+
+    pub fn get_rust_trait_impl(&self, interface: &Class) -> String {
+        let mut output = String::new();
+        output.push_str("impl");
+        if self.is_generic() {
+            output.push('<');
+            output.push_str(self.type_params.join(", ").as_str());
+            output.push('>');
+        }
+        output.push(' ');
+        output.push_str(interface.get_name().as_str());
+        output.push_str(" for ");
+        output.push_str(self.get_generic_name().as_str());
+        output.push_str(" { ");
+        for field in interface.fields.iter() {
+            output.push_str("\n\tfn ");
+            output.push_str(field.get_name().as_str());
+            output.push_str("(&self) -> &");
+            output.push_str(get_rust_type(&field.get_field_type()).as_str());
+            output.push_str(" { \n\t\t&self.");
+            output.push_str(field.get_name().as_str());
+            output.push_str("\n\t}");
+        }
+        output.push_str("\n}\n \n");
+        output
+    }
+}
+
+pub fn get_name_of(name: &str, language: &Language, objects: &[Class]) -> String {
+    match objects.iter().find(|o| o.get_name() == name) {
+        Some(class) => class.get_name_for(language),
+        None => name.to_string(),
+    }
+}
+
+// Javadoc, TSDoc, `///` or a C block comment holding the `///` lines of the schema
+pub fn get_doc_comment(doc: &[String], language: &Language, indent: &str) -> String {
+    let mut comment = String::new();
+    if doc.is_empty() {
+        return comment;
+    }
+    match language {
+        Language::RUST => {
+            for line in doc.iter() {
+                comment.push_str(indent);
+                comment.push_str("///");
+                if !line.is_empty() {
+                    comment.push(' ');
+                    comment.push_str(line.as_str());
+                }
+                comment.push('\n');
+            }
+        }
+        _ => {
+            comment.push_str(indent);
+            if let Language::C = language {
+                comment.push_str("/*\n");
+            } else {
+                comment.push_str("/**\n");
+            }
+            for line in doc.iter() {
+                comment.push_str(indent);
+                comment.push_str(" *");
+                if !line.is_empty() {
+                    comment.push(' ');
+                    comment.push_str(line.as_str());
+                }
+                comment.push('\n');
+            }
+            comment.push_str(indent);
+            comment.push_str(" */\n");
+        }
+    }
+    comment
+}
+
+// `@Deprecated`, `#[deprecated(note = "...")]` or `__attribute__((deprecated("...")))`,
+// TypeScript only has the `@deprecated` doc tag
+pub fn get_deprecated_attribute(
+    deprecated: &Option<String>,
+    language: &Language,
+) -> Option<String> {
+    let note = deprecated.as_ref()?;
+    let attribute = match (language, note.is_empty()) {
+        (Language::JAVA, _) => "@Deprecated".to_string(),
+        (Language::RUST, true) => "#[deprecated]".to_string(),
+        (Language::RUST, false) => format!("#[deprecated(note = \"{}\")]", note),
+        (Language::C, true) => "__attribute__((deprecated))".to_string(),
+        (Language::C, false) => format!("__attribute__((deprecated(\"{}\")))", note),
+        (Language::TYPESCRIPT, _) => return None,
+    };
+    Some(attribute)
+}
+
+fn add_deprecated_tag(doc: &mut Vec<String>, deprecated: &Option<String>) {
+    if let Some(note) = deprecated {
+        doc.push(format!("@deprecated {}", note).trim_end().to_string());
+    }
+}
+
+pub fn get_attributes_for(attributes: &[(Language, String)], language: &Language) -> Vec<String> {
+    attributes
+        .iter()
+        .filter(|(l, _a)| l == language)
+        .map(|(_l, a)| a.clone())
+        .collect()
+}
+
+// the field attributes for `language`, one indented line each
+fn get_attribute_lines(field: &Field, language: &Language) -> String {
+    let mut lines = String::new();
+    for attribute in field.get_attributes_for(language) {
+        lines.push('\t');
+        lines.push_str(attribute.as_str());
+        lines.push('\n');
+    }
+    lines
+}
+
+// renders a default value for `field_type`, e.g. `new ArrayList<>(List.of(1, 2))` in Java
+pub fn get_literal(literal: &Literal, field_type: &FieldType, language: &Language) -> String {
+    match literal {
+        Literal::INTEGER(value) => match (field_type, language) {
+            (FieldType::DOUBLE | FieldType::FLOAT, Language::TYPESCRIPT) => value.to_string(),
+            (FieldType::FLOAT, Language::JAVA) => format!("{:?}f", *value as f64),
+            (FieldType::DOUBLE | FieldType::FLOAT, _) => format!("{:?}", *value as f64),
+            (FieldType::I64 | FieldType::U64, Language::TYPESCRIPT) => format!("{}n", value),
+            (FieldType::I64 | FieldType::U32 | FieldType::U64, Language::JAVA) => {
+                format!("{}L", value)
+            }
+            _ => value.to_string(),
+        },
+        Literal::DOUBLE(value) => match (field_type, language) {
+            (FieldType::FLOAT, Language::JAVA) => format!("{:?}f", value),
+            _ => format!("{:?}", value),
+        },
+        Literal::BOOL(value) => value.to_string(),
+        Literal::STRING(value) => match language {
+            Language::RUST => format!("String::from(\"{}\")", value),
+            _ => format!("\"{}\"", value),
+        },
+        Literal::CHAR(value) => match language {
+            Language::TYPESCRIPT => format!("\"{}\"", value),
+            _ => format!("'{}'", value),
+        },
+        Literal::CONSTANT(name) => name.clone(),
+        Literal::ARRAY(items) => {
+            let item_type = match field_type {
+                FieldType::ARRAY(array_type)
+                | FieldType::FIXEDARRAY(array_type, _)
+                | FieldType::LIST(array_type) => array_type.to_field_type(),
+                _ => FieldType::UNDEFINED,
+            };
+            let items: Vec<String> = items
+                .iter()
+                .map(|i| get_literal(i, &item_type, language))
+                .collect();
+            let items = items.join(", ");
+            let is_list = matches!(field_type, FieldType::LIST(_));
+            match language {
+                Language::JAVA if is_list && items.is_empty() => "new ArrayList<>()".to_string(),
+                Language::JAVA if is_list => format!("new ArrayList<>(List.of({}))", items),
+                Language::JAVA | Language::C => format!("{{{}}}", items),
+                Language::RUST if is_list => format!("vec![{}]", items),
+                _ => format!("[{}]", items),
+            }
+        }
+    }
+}
+
+// `VALUES_LENGTH` for a fixed-length `values` array
+fn get_length_constant_name(field: &Field) -> String {
+    let name = Case::SNAKE.convert(field.get_name().trim_start_matches("r#"));
+    format!("{}_LENGTH", name.to_uppercase())
+}
+
+fn get_java_getter_name(field: &Field) -> String {
+    let mut getter = "get".to_string();
+    let name = field.get_name();
+    let mut chars = name.chars();
+    if let Some(first) = chars.next() {
+        getter.extend(first.to_uppercase());
+        getter.push_str(chars.as_str());
+    }
+    getter
+}
+
+pub fn get_java_type(field_type: &FieldType) -> String {
+    match field_type {
+        FieldType::STRING => "String".to_string(),
+        FieldType::INTEGER => "int".to_string(),
+        FieldType::DOUBLE => "double".to_string(),
+        FieldType::CHAR => "char".to_string(),
+        FieldType::BOOL => "boolean".to_string(),
+        FieldType::I8 => "byte".to_string(),
+        FieldType::I16 => "short".to_string(),
+        FieldType::I64 => "long".to_string(),
+        // Java has no unsigned types so each one takes the next wider signed type
+        FieldType::U8 => "short".to_string(),
+        FieldType::U16 => "int".to_string(),
+        FieldType::U32 | FieldType::U64 => "long".to_string(),
+        FieldType::FLOAT => "float".to_string(),
+        FieldType::DATETIME => "Instant".to_string(),
+        FieldType::DATE => "LocalDate".to_string(),
+        FieldType::DURATION => "Duration".to_string(),
+        FieldType::UUID => "UUID".to_string(),
+        FieldType::DECIMAL => "BigDecimal".to_string(),
+        FieldType::BYTES => "byte[]".to_string(),
+        FieldType::CUSTOM(name) | FieldType::ALIAS(name) => name.clone(),
+        FieldType::ARRAY(array_type) | FieldType::FIXEDARRAY(array_type, _) => {
+            format!("{}[]", get_java_type(&array_type.to_field_type()))
+        }
+        FieldType::LIST(array_type) => format!("List<{}>", get_java_boxed_type(array_type)),
+        FieldType::GENERIC(name, args) => {
+            let args: Vec<String> = args.iter().map(get_java_boxed_type).collect();
+            format!("{}<{}>", name, args.join(", "))
+        }
+        FieldType::TUPLE(items) => {
+            let items: Vec<String> = items.iter().map(get_java_boxed_type).collect();
+            format!("{}<{}>", get_java_tuple_name(items.len()), items.join(", "))
+        }
+        FieldType::UNDEFINED => String::new(),
+    }
+}
+
+// tuples are records nested in the class using them, see `get_java_tuple_records`
+fn get_java_tuple_name(length: usize) -> String {
+    match length {
+        2 => "Pair".to_string(),
+        _ => format!("Tuple{}", length),
+    }
+}
+
+// `public record Pair<T0, T1>(T0 item0, T1 item1) {}`, serialized as a JSON array like the
+// tuples of the other languages
+pub fn get_java_tuple_record(length: usize) -> String {
+    let params: Vec<String> = (0..length).map(|i| format!("T{}", i)).collect();
+    let components: Vec<String> = (0..length).map(|i| format!("T{} item{}", i, i)).collect();
+    let mut output = "\t@JsonFormat(shape = JsonFormat.Shape.ARRAY)\n\tpublic record ".to_string();
+    output.push_str(get_java_tuple_name(length).as_str());
+    output.push_str(format!("<{}>({}) {{}} \n", params.join(", "), components.join(", ")).as_str());
+    output
+}
+
+// import needed for a built-in type, e.g. `java.time.Instant` for `datetime`
+pub fn get_java_import(field_type: &FieldType) -> Option<String> {
+    let import = match field_type {
+        FieldType::DATETIME => "java.time.Instant",
+        FieldType::DATE => "java.time.LocalDate",
+        FieldType::DURATION => "java.time.Duration",
+        FieldType::UUID => "java.util.UUID",
+        FieldType::DECIMAL => "java.math.BigDecimal",
+        _ => return None,
+    };
+    Some(import.to_string())
+}
+
+fn get_java_boxed_type(array_type: &ArrayType) -> String {
+    match get_java_type(&array_type.to_field_type()).as_str() {
+        "int" => "Integer".to_string(),
+        "char" => "Character".to_string(),
+        "byte" => "Byte".to_string(),
+        "short" => "Short".to_string(),
+        "long" => "Long".to_string(),
+        "float" => "Float".to_string(),
+        "double" => "Double".to_string(),
+        "boolean" => "Boolean".to_string(),
+        name => name.to_string(),
+    }
+}
+
+pub fn get_ts_type(field_type: &FieldType) -> String {
+    match field_type {
+        FieldType::STRING => "string".to_string(),
+        FieldType::CHAR => "string".to_string(),
+        FieldType::BOOL => "boolean".to_string(),
+        FieldType::DATETIME => "Date".to_string(),
+        // ISO 8601 text, e.g. `2024-01-31` and `PT1H30M`, decimals keep their precision as text
+        FieldType::DATE | FieldType::DURATION | FieldType::UUID | FieldType::DECIMAL => {
+            "string".to_string()
+        }
+        FieldType::BYTES => "Uint8Array".to_string(),
+        // 64 bit integers do not fit in a double
+        FieldType::I64 | FieldType::U64 => "bigint".to_string(),
+        FieldType::INTEGER
+        | FieldType::DOUBLE
+        | FieldType::I8
+        | FieldType::I16
+        | FieldType::U8
+        | FieldType::U16
+        | FieldType::U32
+        | FieldType::FLOAT => "number".to_string(),
+        FieldType::CUSTOM(name) | FieldType::ALIAS(name) => name.clone(),
+        FieldType::ARRAY(array_type) => format!("{}[]", get_ts_element_type(array_type)),
+        // short fixed-length arrays become tuples so the compiler checks their length
+        FieldType::FIXEDARRAY(array_type, length) if *length <= MAX_TS_TUPLE_LENGTH => {
+            let elements = vec![get_ts_element_type(array_type); *length];
+            format!("[{}]", elements.join(", "))
+        }
+        FieldType::FIXEDARRAY(array_type, _) => format!("{}[]", get_ts_element_type(array_type)),
+        FieldType::LIST(array_type) => format!("Array<{}>", get_ts_element_type(array_type)),
+        FieldType::GENERIC(name, args) => {
+            let args: Vec<String> = args.iter().map(get_ts_element_type).collect();
+            format!("{}<{}>", name, args.join(", "))
+        }
+        FieldType::TUPLE(items) => {
+            let items: Vec<String> = items.iter().map(get_ts_element_type).collect();
+            format!("[{}]", items.join(", "))
+        }
+        FieldType::UNDEFINED => String::new(),
+    }
+}
+
+const MAX_TS_TUPLE_LENGTH: usize = 16;
+
+// class held by a field directly or as the elements of an array
+fn get_ts_json_class(field_type: &FieldType) -> Option<String> {
+    match field_type {
+        FieldType::CUSTOM(name)
+        | FieldType::ARRAY(ArrayType::CUSTOM(name))
+        | FieldType::FIXEDARRAY(ArrayType::CUSTOM(name), _)
+        | FieldType::LIST(ArrayType::CUSTOM(name)) => Some(name.clone()),
+        _ => None,
+    }
+}
+
+// `Address.fromJSON(home)` for a parsed `value` whose class has a `fromJSON()`
+fn get_ts_json_conversion(
+    field_type: &FieldType,
+    value: &str,
+    objects: &[Class],
+) -> Option<String> {
+    let name = get_ts_json_class(field_type)?;
+    if !objects
+        .iter()
+        .any(|o| o.get_name() == name && o.has_ts_from_json(objects))
+    {
+        return None;
+    }
+    let conversion = match field_type {
+        FieldType::CUSTOM(_) => format!("{}.fromJSON({})", name, value),
+        FieldType::FIXEDARRAY(_, _) => format!(
+            "{}.map((item: any) => {}.fromJSON(item)) as {}",
+            value,
+            name,
+            get_ts_type(field_type)
+        ),
+        _ => format!("{}.map((item: any) => {}.fromJSON(item))", value, name),
+    };
+    Some(conversion)
+}
+
+fn get_ts_element_type(array_type: &ArrayType) -> String {
+    get_ts_type(&array_type.to_field_type())
+}
+
+pub fn get_c_type(field_type: &FieldType) -> String {
+    match field_type {
+        FieldType::STRING => "char*".to_string(),
+        FieldType::INTEGER => "int".to_string(),
+        FieldType::DOUBLE => "double".to_string(),
+        FieldType::CHAR => "char".to_string(),
+        FieldType::BOOL => "bool".to_string(),
+        FieldType::I8 => "int8_t".to_string(),
+        FieldType::I16 => "int16_t".to_string(),
+        FieldType::I64 => "int64_t".to_string(),
+        FieldType::U8 => "uint8_t".to_string(),
+        FieldType::U16 => "uint16_t".to_string(),
+        FieldType::U32 => "uint32_t".to_string(),
+        FieldType::U64 => "uint64_t".to_string(),
+        FieldType::FLOAT => "float".to_string(),
+        FieldType::DATETIME | FieldType::DURATION => "int64_t".to_string(),
+        FieldType::DATE => "int32_t".to_string(),
+        FieldType::UUID | FieldType::DECIMAL => "char*".to_string(),
+        FieldType::BYTES => "uint8_t*".to_string(),
+        FieldType::CUSTOM(name) => format!("struct {}", name),
+        // aliases are `typedef`s and need no `struct` keyword
+        FieldType::ALIAS(name) => name.clone(),
+        FieldType::ARRAY(ArrayType::STRING) | FieldType::LIST(ArrayType::STRING) => {
+            "char*".to_string()
+        }
+        FieldType::ARRAY(ArrayType::CUSTOM(name)) | FieldType::LIST(ArrayType::CUSTOM(name)) => {
+            format!("{}*", name)
+        }
+        FieldType::ARRAY(array_type) | FieldType::LIST(array_type) => {
+            format!("{}*", get_c_type(&array_type.to_field_type()))
+        }
+        // the length is part of the declarator, see `get_c_fields`
+        FieldType::FIXEDARRAY(array_type, _) => get_c_type(&array_type.to_field_type()),
+        FieldType::GENERIC(name, args) => {
+            format!("struct {}", get_c_generic_name(name.as_str(), args))
+        }
+        // an unnamed struct with one `itemN` member per element
+        FieldType::TUPLE(items) => {
+            let mut members = String::new();
+            for (i, item) in items.iter().enumerate() {
+                members.push_str(
+                    format!("{} item{}; ", get_c_type(&item.to_field_type()), i).as_str(),
+                );
+            }
+            format!("struct {{ {}}}", members)
+        }
+        FieldType::UNDEFINED => String::new(),
+    }
+}
+
+// a pointer to the whole tuple would still embed its members by value, so a tuple on a cycle
+// keeps its class members behind pointers instead
+fn get_c_indirect_tuple_type(items: &[ArrayType]) -> String {
+    let mut members = String::new();
+    for (i, item) in items.iter().enumerate() {
+        let mut member_type = get_c_type(&item.to_field_type());
+        if let ArrayType::CUSTOM(_) | ArrayType::ALIAS(_) = item {
+            member_type.push('*');
+        }
+        members.push_str(format!("{} item{}; ", member_type, i).as_str());
+    }
+    format!("struct {{ {}}}", members)
+}
+
+// how a built-in type without a C equivalent is stored, emitted next to the field
+pub fn get_c_type_comment(field_type: &FieldType) -> Option<String> {
+    let comment = match field_type {
+        FieldType::DATETIME => "milliseconds since the Unix epoch, UTC",
+        FieldType::DATE => "days since 1970-01-01",
+        FieldType::DURATION => "milliseconds",
+        FieldType::UUID => "36 character hyphenated UUID",
+        FieldType::DECIMAL => "decimal number as text",
+        FieldType::BYTES => "byte buffer",
+        _ => return None,
+    };
+    Some(comment.to_string())
+}
+
+// fixed width integers need `<stdint.h>`
+pub fn is_c_sized_type(field_type: &FieldType) -> bool {
+    match field_type {
+        FieldType::ARRAY(array_type)
+        | FieldType::FIXEDARRAY(array_type, _)
+        | FieldType::LIST(array_type) => is_c_sized_type(&array_type.to_field_type()),
+        FieldType::GENERIC(_, args) | FieldType::TUPLE(args) => {
+            args.iter().any(|a| is_c_sized_type(&a.to_field_type()))
+        }
+        _ => get_c_type(field_type).trim_end_matches('*').ends_with("_t"),
+    }
+}
+
+// `Page<User>` becomes `Page_User`, one struct per concrete instantiation
+pub fn get_c_generic_name(name: &str, args: &[ArrayType]) -> String {
+    let mut generic_name = name.to_string();
+    for arg in args.iter() {
+        generic_name.push('_');
+        match arg {
+            ArrayType::INTEGER => generic_name.push_str("int"),
+            ArrayType::STRING => generic_name.push_str("String"),
+            ArrayType::CHAR => generic_name.push_str("char"),
+            ArrayType::DOUBLE => generic_name.push_str("double"),
+            ArrayType::BOOL => generic_name.push_str("bool"),
+            ArrayType::FLOAT => generic_name.push_str("float"),
+            ArrayType::DATETIME => generic_name.push_str("datetime"),
+            ArrayType::DATE => generic_name.push_str("date"),
+            ArrayType::DURATION => generic_name.push_str("duration"),
+            ArrayType::UUID => generic_name.push_str("uuid"),
+            ArrayType::DECIMAL => generic_name.push_str("decimal"),
+            ArrayType::BYTES => generic_name.push_str("bytes"),
+            ArrayType::CUSTOM(name) | ArrayType::ALIAS(name) => {
+                generic_name.push_str(name.as_str())
+            }
+            _ => generic_name.push_str(get_rust_element_type(arg).as_str()),
+        }
+    }
+    generic_name
+}
+
+pub fn get_rust_type(field_type: &FieldType) -> String {
+    match field_type {
+        FieldType::STRING => "String".to_string(),
+        FieldType::INTEGER => "i32".to_string(),
+        FieldType::DOUBLE => "f64".to_string(),
+        FieldType::CHAR => "char".to_string(),
+        FieldType::BOOL => "bool".to_string(),
+        FieldType::I8 => "i8".to_string(),
+        FieldType::I16 => "i16".to_string(),
+        FieldType::I64 => "i64".to_string(),
+        FieldType::U8 => "u8".to_string(),
+        FieldType::U16 => "u16".to_string(),
+        FieldType::U32 => "u32".to_string(),
+        FieldType::U64 => "u64".to_string(),
+        FieldType::FLOAT => "f32".to_string(),
+        // aliases declared at the top of the file, see `get_rust_type_alias`
+        FieldType::DATETIME => "DateTime".to_string(),
+        FieldType::DATE => "Date".to_string(),
+        FieldType::DURATION => "Duration".to_string(),
+        FieldType::UUID => "Uuid".to_string(),
+        FieldType::DECIMAL => "Decimal".to_string(),
+        FieldType::BYTES => "Vec<u8>".to_string(),
+        FieldType::CUSTOM(name) | FieldType::ALIAS(name) => name.clone(),
+        FieldType::ARRAY(array_type) => format!("[{}]", get_rust_element_type(array_type)),
+        FieldType::FIXEDARRAY(array_type, length) => {
+            format!("[{}; {}]", get_rust_element_type(array_type), length)
+        }
+        FieldType::LIST(array_type) => format!("Vec<{}>", get_rust_element_type(array_type)),
+        FieldType::GENERIC(name, args) => {
+            let args: Vec<String> = args.iter().map(get_rust_element_type).collect();
+            format!("{}<{}>", name, args.join(", "))
+        }
+        FieldType::TUPLE(items) => {
+            let items: Vec<String> = items.iter().map(get_rust_element_type).collect();
+            format!("({})", items.join(", "))
+        }
+        FieldType::UNDEFINED => String::new(),
+    }
+}
+
+fn get_alias_target_of(field_type: &FieldType, objects: &[Class]) -> Option<FieldType> {
+    match field_type {
+        FieldType::ALIAS(name) => objects
+            .iter()
+            .find(|o| o.is_alias() && &o.get_name() == name)
+            .map(|alias| alias.get_alias_target()),
+        _ => None,
+    }
+}
+
+// `literal` as a Rust value of `field_type`, wrapped in the newtype of every alias it goes through
+fn get_rust_newtype_literal(
+    literal: &Literal,
+    field_type: &FieldType,
+    objects: &[Class],
+) -> String {
+    if let (FieldType::ALIAS(name), Some(target)) =
+        (field_type, get_alias_target_of(field_type, objects))
+    {
+        return format!(
+            "{}({})",
+            name,
+            get_rust_newtype_literal(literal, &target, objects)
+        );
+    }
+    match (field_type, literal) {
+        (
+            FieldType::ARRAY(array_type)
+            | FieldType::FIXEDARRAY(array_type, _)
+            | FieldType::LIST(array_type),
+            Literal::ARRAY(items),
+        ) if matches!(array_type, ArrayType::ALIAS(_)) => {
+            let item_type = array_type.to_field_type();
+            let items: Vec<String> = items
+                .iter()
+                .map(|i| get_rust_newtype_literal(i, &item_type, objects))
+                .collect();
+            match field_type {
+                FieldType::LIST(_) => format!("vec![{}]", items.join(", ")),
+                _ => format!("[{}]", items.join(", ")),
+            }
+        }
+        _ => get_literal(literal, field_type, &Language::RUST),
+    }
+}
+
+// `.0` for every newtype between a field declared through aliases and its value
+pub fn get_rust_newtype_access(field_type: &FieldType, objects: &[Class]) -> String {
+    match get_alias_target_of(field_type, objects) {
+        Some(target) => format!(".0{}", get_rust_newtype_access(&target, objects)),
+        None => String::new(),
+    }
+}
+
+// indirect fields are an `Option` that starts as `None`
+fn get_rust_field_default(field: &Field) -> String {
+    if field.is_indirect() {
+        return "Default::default()".to_string();
+    }
+    get_rust_default_value(&field.get_field_type())
+}
+
+// serde and `Default` are only implemented for arrays of up to 32 elements
+const MAX_RUST_DERIVED_ARRAY_LENGTH: usize = 32;
+
+fn is_rust_big_array(field_type: &FieldType) -> bool {
+    matches!(field_type, FieldType::FIXEDARRAY(_, length) if *length > MAX_RUST_DERIVED_ARRAY_LENGTH)
+}
+
+// value of a Rust field without a schema default
+pub fn get_rust_default_value(field_type: &FieldType) -> String {
+    let get_element_value =
+        |array_type: &ArrayType| get_rust_default_value(&array_type.to_field_type());
+    match field_type {
+        // `std::time::SystemTime` has no `Default`, and chrono's `DateTime` converts from it too
+        FieldType::DATETIME => "DateTime::from(std::time::UNIX_EPOCH)".to_string(),
+        // `Default` is only implemented for arrays of up to 32 elements
+        FieldType::FIXEDARRAY(array_type, _) => {
+            format!("std::array::from_fn(|_| {})", get_element_value(array_type))
+        }
+        FieldType::TUPLE(items)
+            if items
+                .iter()
+                .any(|i| get_element_value(i) != "Default::default()") =>
+        {
+            let values: Vec<String> = items.iter().map(get_element_value).collect();
+            format!("({})", values.join(", "))
+        }
+        _ => "Default::default()".to_string(),
+    }
+}
+
+// `type DateTime = chrono::DateTime<chrono::Utc>;` when the `chrono` crate is enabled and a
+// dependency free type otherwise
+pub fn get_rust_type_alias(field_type: &FieldType, crates: &[String]) -> Option<String> {
+    let uses = |name: &str| crates.iter().any(|c| c == name);
+    let target = match field_type {
+        FieldType::DATETIME if uses("chrono") => "chrono::DateTime<chrono::Utc>",
+        FieldType::DATETIME => "std::time::SystemTime",
+        FieldType::DATE if uses("chrono") => "chrono::NaiveDate",
+        FieldType::DATE => "String",
+        FieldType::DURATION if uses("chrono") => "chrono::Duration",
+        FieldType::DURATION => "std::time::Duration",
+        FieldType::UUID if uses("uuid") => "uuid::Uuid",
+        FieldType::UUID => "String",
+        FieldType::DECIMAL if uses("rust_decimal") => "rust_decimal::Decimal",
+        FieldType::DECIMAL => "String",
+        _ => return None,
+    };
+    Some(format!("type {} = {};", get_rust_type(field_type), target))
+}
+
+fn get_rust_element_type(array_type: &ArrayType) -> String {
+    get_rust_type(&array_type.to_field_type())
+}
+
+// `field_type` with every alias in it replaced by the type it stands for
+pub fn get_inlined_type(field_type: &FieldType, objects: &[Class]) -> FieldType {
+    field_type.map_elements(&|a| inline_alias(a, objects))
+}
+
+fn inline_alias(array_type: &ArrayType, objects: &[Class]) -> ArrayType {
+    let name = match array_type {
+        ArrayType::ALIAS(name) => name,
+        _ => return array_type.clone(),
+    };
+    let target = objects
+        .iter()
+        .find(|o| o.is_alias() && &o.get_name() == name)
+        .and_then(|alias| alias.get_alias_target().to_array_type());
+    match target {
+        Some(target) => inline_alias(&target, objects),
+        None => array_type.clone(),
+    }
+}
+
+fn substitute_array_type(
+    array_type: &ArrayType,
+    params: &[String],
+    args: &[ArrayType],
+) -> ArrayType {
+    if let ArrayType::CUSTOM(name) = array_type {
+        if let Some(index) = params.iter().position(|p| p == name) {
+            return args[index].clone();
+        }
+    }
+    array_type.clone()
+}
+
+fn substitute_type(field_type: &FieldType, params: &[String], args: &[ArrayType]) -> FieldType {
+    match field_type {
+        FieldType::CUSTOM(name) => match params.iter().position(|p| p == name) {
+            Some(index) => args[index].to_field_type(),
+            None => field_type.clone(),
+        },
+        FieldType::ARRAY(array_type) => {
+            FieldType::ARRAY(substitute_array_type(array_type, params, args))
+        }
+        FieldType::FIXEDARRAY(array_type, length) => {
+            FieldType::FIXEDARRAY(substitute_array_type(array_type, params, args), *length)
+        }
+        FieldType::LIST(array_type) => {
+            FieldType::LIST(substitute_array_type(array_type, params, args))
+        }
+        FieldType::GENERIC(name, inner) => FieldType::GENERIC(
+            name.clone(),
+            inner
+                .iter()
+                .map(|a| substitute_array_type(a, params, args))
+                .collect(),
+        ),
+        _ => field_type.clone(),
+    }
 }
@@ -0,0 +1,276 @@
+use crate::models::{Constraint, FieldType, Language, Literal};
+use crate::objects::{get_literal, get_rust_newtype_access, Class, Field};
+
+impl Class {
+    pub fn has_constraints(&self) -> bool {
+        self.fields.iter().any(|f| !f.get_constraints().is_empty())
+    }
+
+    pub fn has_pattern_constraints(&self) -> bool {
+        self.fields.iter().any(|f| {
+            f.get_constraints()
+                .iter()
+                .any(|c| matches!(c, Constraint::PATTERN(_)))
+        })
+    }
+
+    pub fn get_ts_validate_method(&self) -> String {
+        let mut output = String::new();
+        output.push_str("\n\tvalidate(): string[] { \n\t\tconst errors: string[] = []; \n");
+        for field in self.fields.iter() {
+            let accessor = format!("this.{}", field.get_name());
+            for (condition, message) in get_violations(field, &accessor, &Language::TYPESCRIPT) {
+                output.push_str("\t\tif (");
+                output.push_str(condition.as_str());
+                output.push_str(") errors.push(\"");
+                output.push_str(message.as_str());
+                output.push_str("\"); \n");
+            }
+        }
+        output.push_str("\t\treturn errors; \n\t} \n");
+        output
+    }
+
+    pub fn get_rust_validate_impl(&self, objects: &[Class], newtypes: bool) -> String {
+        let mut output = String::new();
+        output.push_str("impl");
+        if self.is_generic() {
+            output.push('<');
+            output.push_str(self.type_params.join(", ").as_str());
+            output.push('>');
+        }
+        output.push(' ');
+        output.push_str(self.get_generic_name().as_str());
+        output.push_str(" { \n\tpub fn validate(&self) -> Result<(), Vec<String>> { \n");
+        // each pattern is compiled on the first call only
+        for field in self.fields.iter() {
+            for (index, pattern) in get_patterns(field).iter().enumerate() {
+                output.push_str(
+                    format!(
+                        "\t\tstatic {}: std::sync::LazyLock<regex::Regex> = \n\t\t\t\
+                         std::sync::LazyLock::new(|| regex::Regex::new({}).unwrap()); \n",
+                        get_rust_pattern_name(field, index),
+                        get_rust_raw_string(pattern)
+                    )
+                    .as_str(),
+                );
+            }
+        }
+        output.push_str("\t\tlet mut errors = Vec::new(); \n");
+        for field in self.fields.iter() {
+            let mut accessor = format!("self.{}", field.get_name());
+            if newtypes {
+                accessor
+                    .push_str(get_rust_newtype_access(&field.get_field_type(), objects).as_str());
+            }
+            for (condition, message) in get_violations(field, &accessor, &Language::RUST) {
+                output.push_str("\t\tif ");
+                output.push_str(condition.as_str());
+                output.push_str(" { \n\t\t\terrors.push(String::from(\"");
+                output.push_str(message.as_str());
+                output.push_str("\")); \n\t\t} \n");
+            }
+        }
+        output.push_str("\t\tif errors.is_empty() { \n\t\t\tOk(()) \n\t\t} else { \n");
+        output.push_str("\t\t\tErr(errors) \n\t\t} \n\t} \n}\n \n");
+        output
+    }
+
+    // `bool <Name>_validate(const struct <Name>* value)` returning false on the first violation
+    pub fn get_c_validate_function(&self) -> String {
+        let mut output = String::new();
+        if self.has_pattern_constraints() {
+            output.push_str(
+                "static bool matches_pattern(const char* text, const char* pattern) { \n",
+            );
+            output.push_str("\tregex_t regex; \n\tbool matched; \n");
+            output.push_str("\tif (text == NULL || regcomp(&regex, pattern, REG_EXTENDED | REG_NOSUB) != 0) return false; \n");
+            output.push_str("\tmatched = regexec(&regex, text, 0, NULL, 0) == 0; \n");
+            output.push_str("\tregfree(&regex); \n\treturn matched; \n} \n \n");
+        }
+        output.push_str("bool ");
+        output.push_str(self.get_name().as_str());
+        output.push_str("_validate(const struct ");
+        output.push_str(self.get_name().as_str());
+        output.push_str("* value) { \n");
+        for field in self.fields.iter() {
+            let accessor = format!("value->{}", field.get_name());
+            for (condition, message) in get_violations(field, &accessor, &Language::C) {
+                output.push_str("\tif (");
+                output.push_str(condition.as_str());
+                output.push_str(") return false; /* ");
+                output.push_str(message.as_str());
+                output.push_str(" */ \n");
+            }
+        }
+        output.push_str("\treturn true; \n} \n \n");
+        output
+    }
+}
+
+// Bean Validation annotations, each on its own line before the field
+pub fn get_java_constraints(field: &Field) -> String {
+    let mut output = String::new();
+    for constraint in field.get_constraints().iter() {
+        output.push('\t');
+        match constraint {
+            Constraint::MIN(Literal::DOUBLE(value)) => {
+                output.push_str(format!("@DecimalMin(\"{:?}\")", value).as_str())
+            }
+            Constraint::MAX(Literal::DOUBLE(value)) => {
+                output.push_str(format!("@DecimalMax(\"{:?}\")", value).as_str())
+            }
+            Constraint::MIN(value) => output.push_str(format!("@Min({})", literal(value)).as_str()),
+            Constraint::MAX(value) => output.push_str(format!("@Max({})", literal(value)).as_str()),
+            Constraint::LENGTH(min, max) => {
+                let mut bounds = Vec::new();
+                if let Some(min) = min {
+                    bounds.push(format!("min = {}", literal(min)));
+                }
+                if let Some(max) = max {
+                    bounds.push(format!("max = {}", literal(max)));
+                }
+                output.push_str(format!("@Size({})", bounds.join(", ")).as_str());
+            }
+            Constraint::PATTERN(pattern) => output
+                .push_str(format!("@Pattern(regexp = \"{}\")", escape_string(pattern)).as_str()),
+            Constraint::NONEMPTY => output.push_str("@NotEmpty"),
+        }
+        output.push('\n');
+    }
+    output
+}
+
+// a regular expression written inside a `"..."` literal of Java, TypeScript or C, where `\d`
+// would otherwise be read as an escape of the host language
+fn escape_string(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+// `r#"..."#` with enough `#` that no `"#` in the text ends it early
+fn get_rust_raw_string(text: &str) -> String {
+    let mut hashes = "#".to_string();
+    while text.contains(format!("\"{}", hashes).as_str()) {
+        hashes.push('#');
+    }
+    format!("r{}\"{}\"{}", hashes, text, hashes)
+}
+
+fn get_patterns(field: &Field) -> Vec<String> {
+    field
+        .get_constraints()
+        .into_iter()
+        .filter_map(|c| match c {
+            Constraint::PATTERN(pattern) => Some(pattern),
+            _ => None,
+        })
+        .collect()
+}
+
+// `EMAIL_PATTERN`, then `EMAIL_PATTERN_2` and so on for further patterns of the same field
+fn get_rust_pattern_name(field: &Field, index: usize) -> String {
+    let mut name = field.get_name().trim_start_matches("r#").to_uppercase();
+    name.push_str("_PATTERN");
+    if index > 0 {
+        name.push_str(format!("_{}", index + 1).as_str());
+    }
+    name
+}
+
+fn literal(value: &Literal) -> String {
+    get_literal(value, &FieldType::UNDEFINED, &Language::JAVA)
+}
+
+// every check for `field` as a (condition that is true when invalid, message) pair
+fn get_violations(field: &Field, accessor: &str, language: &Language) -> Vec<(String, String)> {
+    let mut violations = Vec::new();
+    let name = field.get_name();
+    let field_type = field.get_value_type();
+    let is_string = field_type == FieldType::STRING;
+    let length = match language {
+        Language::TYPESCRIPT => format!("{}.length", accessor),
+        Language::RUST if is_string => format!("{}.chars().count()", accessor),
+        Language::RUST => format!("{}.len()", accessor),
+        _ => format!("strlen({})", accessor),
+    };
+    // C arrays and lists are bare pointers so only strings have a known length
+    let has_known_length = *language != Language::C || is_string;
+    let mut patterns = 0;
+    for constraint in field.get_constraints().iter() {
+        match constraint {
+            Constraint::MIN(value) => violations.push((
+                format!(
+                    "{} < {}",
+                    accessor,
+                    get_literal(value, &field_type, language)
+                ),
+                format!("{} must be at least {}", name, literal(value)),
+            )),
+            Constraint::MAX(value) => violations.push((
+                format!(
+                    "{} > {}",
+                    accessor,
+                    get_literal(value, &field_type, language)
+                ),
+                format!("{} must be at most {}", name, literal(value)),
+            )),
+            Constraint::LENGTH(min, max) if has_known_length => {
+                if let Some(min) = min.as_ref().map(literal) {
+                    let null_check = match language {
+                        Language::C => format!("{} == NULL || ", accessor),
+                        _ => String::new(),
+                    };
+                    violations.push((
+                        format!("{}{} < {}", null_check, length, min),
+                        format!("{} must have a length of at least {}", name, min),
+                    ));
+                }
+                if let Some(max) = max.as_ref().map(literal) {
+                    let null_check = match language {
+                        Language::C => format!("{} != NULL && ", accessor),
+                        _ => String::new(),
+                    };
+                    violations.push((
+                        format!("{}{} > {}", null_check, length, max),
+                        format!("{} must have a length of at most {}", name, max),
+                    ));
+                }
+            }
+            Constraint::LENGTH(_, _) => (),
+            Constraint::NONEMPTY => {
+                let condition = match language {
+                    Language::TYPESCRIPT => format!("{} === 0", length),
+                    Language::RUST => format!("{}.is_empty()", accessor),
+                    _ if is_string => format!("{} == NULL || {} == 0", accessor, length),
+                    _ => format!("{} == NULL", accessor),
+                };
+                violations.push((condition, format!("{} must not be empty", name)));
+            }
+            Constraint::PATTERN(pattern) => {
+                let condition = match language {
+                    Language::TYPESCRIPT => format!(
+                        "!new RegExp(\"{}\").test({})",
+                        escape_string(pattern),
+                        accessor
+                    ),
+                    Language::RUST => format!(
+                        "!{}.is_match(&{})",
+                        get_rust_pattern_name(field, patterns),
+                        accessor
+                    ),
+                    _ => format!(
+                        "!matches_pattern({}, \"{}\")",
+                        accessor,
+                        escape_string(pattern)
+                    ),
+                };
+                patterns += 1;
+                violations.push((
+                    condition,
+                    format!("{} does not match the required pattern", name),
+                ));
+            }
+        }
+    }
+    violations
+}
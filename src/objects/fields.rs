@@ -1,10 +1,27 @@
-use crate::models::{Access, FieldType};
+use crate::models::{Access, Case, Constraint, FieldType, Language, Literal};
+use crate::objects::{get_attributes_for, get_deprecated_attribute};
 
 #[derive(Clone, Debug)]
 pub struct Field {
     pub field_type: FieldType,
     pub name: String,
     pub access: Access,
+    pub doc: Vec<String>,
+    pub default: Option<Literal>,
+    pub constraints: Vec<Constraint>,
+    // set for by-value fields that are part of a type cycle
+    pub indirect: bool,
+    pub renames: Vec<(Language, String)>,
+    // schema name kept for serialization when the emitted name differs from it
+    pub wire_name: Option<String>,
+    // constant giving the length of a `[T; NAME]` array until constants are resolved
+    pub length_constant: Option<String>,
+    pub attributes: Vec<(Language, String)>,
+    pub deprecated: Option<String>,
+    pub line: usize,
+    // type the default and constraints are written for when the field is declared through an
+    // alias, e.g. `String` for `id: UserId`
+    pub value_type: Option<FieldType>,
 }
 
 impl Field {
@@ -13,18 +30,112 @@ impl Field {
             field_type,
             name,
             access,
+            doc: Vec::new(),
+            default: None,
+            constraints: Vec::new(),
+            indirect: false,
+            renames: Vec::new(),
+            wire_name: None,
+            length_constant: None,
+            attributes: Vec::new(),
+            deprecated: None,
+            line: 0,
+            value_type: None,
         }
     }
 
+    pub fn set_line(&mut self, line: usize) {
+        self.line = line;
+    }
+
+    pub fn add_rename(&mut self, language: Language, name: &str) {
+        self.renames.retain(|(l, _n)| *l != language);
+        self.renames.push((language, name.to_string()));
+    }
+
+    pub fn add_attribute(&mut self, language: Language, attribute: &str) {
+        self.attributes.push((language, attribute.to_string()));
+    }
+
+    pub fn get_attributes_for(&self, language: &Language) -> Vec<String> {
+        let mut attributes: Vec<String> = get_deprecated_attribute(&self.deprecated, language)
+            .into_iter()
+            .collect();
+        attributes.extend(get_attributes_for(&self.attributes, language));
+        attributes
+    }
+
+    pub fn set_deprecated(&mut self, note: &str) {
+        self.deprecated = Some(note.to_string());
+    }
+
+    pub fn set_indirect(&mut self, indirect: bool) {
+        self.indirect = indirect;
+    }
+
+    pub fn add_constraint(&mut self, constraint: Constraint) {
+        self.constraints.push(constraint);
+    }
+
+    pub fn set_default(&mut self, default: Literal) {
+        self.default = Some(default);
+    }
+
+    pub fn set_length_constant(&mut self, name: &str) {
+        self.length_constant = Some(name.to_string());
+    }
+
+    pub fn set_doc(&mut self, doc: &[String]) {
+        self.doc = doc.to_owned();
+    }
+
     pub fn get_name(&self) -> String {
         self.name.clone()
     }
 
+    // the `@rename(...)` override for `language`, otherwise the schema name in `case`
+    pub fn get_name_for(&self, language: &Language, case: &Case) -> String {
+        match self.renames.iter().find(|(l, _n)| l == language) {
+            Some((_l, name)) => name.clone(),
+            None => case.convert(self.name.as_str()),
+        }
+    }
+
+    pub fn get_wire_name(&self) -> Option<String> {
+        self.wire_name.clone()
+    }
+
+    pub fn get_length_constant(&self) -> Option<String> {
+        self.length_constant.clone()
+    }
+
     pub fn get_field_type(&self) -> FieldType {
         self.field_type.clone()
     }
 
+    pub fn get_value_type(&self) -> FieldType {
+        self.value_type
+            .clone()
+            .unwrap_or_else(|| self.field_type.clone())
+    }
+
     pub fn get_access(&self) -> Access {
         self.access.clone()
     }
+
+    pub fn get_doc(&self) -> Vec<String> {
+        self.doc.clone()
+    }
+
+    pub fn get_default(&self) -> Option<Literal> {
+        self.default.clone()
+    }
+
+    pub fn get_constraints(&self) -> Vec<Constraint> {
+        self.constraints.clone()
+    }
+
+    pub fn is_indirect(&self) -> bool {
+        self.indirect
+    }
 }
@@ -1,5 +1,8 @@
 mod class;
+mod constraints;
 mod fields;
+mod unions;
 
 pub use class::*;
+pub use constraints::*;
 pub use fields::*;
@@ -0,0 +1,144 @@
+use crate::models::{Case, FieldType, Language};
+use crate::objects::{get_c_type, get_doc_comment, get_rust_type, get_ts_type, Class, Field};
+
+// unions are declared as `pub union Shape(kind) { circle: Circle, rectangle: Rectangle }`
+// where each field is a variant, its name the tag value and its type the payload
+impl Class {
+    // the tag field, `type` unless given in the declaration
+    pub fn get_discriminator(&self) -> String {
+        match &self.discriminator {
+            Some(d) => d.clone(),
+            None => "type".to_string(),
+        }
+    }
+
+    // payload class names of every variant
+    pub fn get_variant_types(&self) -> Vec<String> {
+        self.fields
+            .iter()
+            .flat_map(|f| f.get_field_type().get_referenced_types())
+            .collect()
+    }
+
+    pub fn get_rust_enum_variants(&self) -> String {
+        let mut variants = String::new();
+        for field in self.fields.iter() {
+            variants.push('\n');
+            variants.push_str(get_doc_comment(&field.get_doc(), &Language::RUST, "\t").as_str());
+            variants.push_str(format!("\t#[serde(rename = \"{}\")]\n", get_tag(field)).as_str());
+            variants.push('\t');
+            variants.push_str(Case::PASCAL.convert(get_tag(field).as_str()).as_str());
+            variants.push('(');
+            if field.is_indirect() {
+                variants.push_str("Box<");
+                variants.push_str(get_rust_type(&field.get_field_type()).as_str());
+                variants.push('>');
+            } else {
+                variants.push_str(get_rust_type(&field.get_field_type()).as_str());
+            }
+            variants.push_str("),");
+        }
+        variants.push('\n');
+        variants
+    }
+
+    // the first variant holding the default of its payload, so that fields of the union have a
+    // `Default` like those of any other generated type
+    pub fn get_rust_enum_default_impl(&self) -> String {
+        let field = match self.fields.first() {
+            Some(f) => f,
+            None => return String::new(),
+        };
+        format!(
+            "impl Default for {} {{ \n\tfn default() -> Self {{ \n\t\t{}::{}(Default::default())\n\t}}\n}}\n \n",
+            self.get_name(),
+            self.get_name(),
+            Case::PASCAL.convert(get_tag(field).as_str())
+        )
+    }
+
+    // `| ({ kind: "circle" } & Circle)` per variant
+    pub fn get_ts_union_members(&self) -> String {
+        let mut members = String::new();
+        for field in self.fields.iter() {
+            members.push_str("\n\t| ({ ");
+            members.push_str(self.get_discriminator().as_str());
+            members.push_str(format!(": \"{}\" }} & ", get_tag(field)).as_str());
+            members.push_str(get_ts_type(&field.get_field_type()).as_str());
+            members.push(')');
+        }
+        members
+    }
+
+    pub fn get_java_subtypes(&self) -> String {
+        let mut subtypes = String::new();
+        subtypes.push_str("@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, property = \"");
+        subtypes.push_str(self.get_discriminator().as_str());
+        subtypes.push_str("\")\n@JsonSubTypes({\n");
+        for field in self.fields.iter() {
+            subtypes.push_str("\t@JsonSubTypes.Type(value = ");
+            subtypes.push_str(get_variant_name(field).as_str());
+            subtypes.push_str(format!(".class, name = \"{}\"),\n", get_tag(field)).as_str());
+        }
+        subtypes.push_str("})\n");
+        subtypes
+    }
+
+    // one `Shape_CIRCLE` constant per variant
+    pub fn get_c_kind_enum(&self) -> String {
+        let mut output = String::new();
+        output.push_str("enum ");
+        output.push_str(self.get_c_kind_name().as_str());
+        output.push_str(" { \n");
+        for field in self.fields.iter() {
+            output.push('\t');
+            output.push_str(self.get_c_kind_constant(field).as_str());
+            output.push_str(", \n");
+        }
+        output.push_str("}; \n \n");
+        output
+    }
+
+    pub fn get_c_union_members(&self) -> String {
+        let mut members = String::new();
+        for field in self.fields.iter() {
+            members.push_str(get_doc_comment(&field.get_doc(), &Language::C, "\t\t").as_str());
+            members.push_str("\t\t");
+            members.push_str(get_c_type(&field.get_field_type()).as_str());
+            if field.is_indirect() {
+                members.push('*');
+            }
+            members.push(' ');
+            members.push_str(field.get_name().as_str());
+            members.push_str("; \n");
+        }
+        members
+    }
+
+    pub fn get_c_kind_name(&self) -> String {
+        format!("{}_Kind", self.get_name())
+    }
+
+    fn get_c_kind_constant(&self, field: &Field) -> String {
+        format!(
+            "{}_{}",
+            self.get_name(),
+            Case::SNAKE.convert(get_tag(field).as_str()).to_uppercase()
+        )
+    }
+}
+
+// the tag value of a variant is its schema name, kept on the wire across languages
+fn get_tag(field: &Field) -> String {
+    match field.get_wire_name() {
+        Some(name) => name,
+        None => field.get_name(),
+    }
+}
+
+fn get_variant_name(field: &Field) -> String {
+    match field.get_field_type() {
+        FieldType::CUSTOM(name) => name,
+        _ => String::new(),
+    }
+}
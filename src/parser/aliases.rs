@@ -0,0 +1,110 @@
+use crate::common::handle_result_error;
+use crate::common::MError;
+use crate::models::{ArrayType, Constraint, FieldType};
+use crate::objects::{get_inlined_type, Field};
+use crate::parser::{get_constraint_error, literal_matches_type, SymbolTable};
+
+// the alias an alias stands for, if any
+fn get_alias_target_name(symbol_table: &SymbolTable, name: &str) -> Option<String> {
+    let alias = symbol_table
+        .get_objects()
+        .into_iter()
+        .find(|o| o.is_alias() && o.get_name() == name)?;
+    match alias.get_alias_target().to_array_type() {
+        Some(ArrayType::CUSTOM(target)) => Some(target),
+        _ => None,
+    }
+}
+
+// rejects `type A = B` and `type B = A`, which would never resolve to a concrete type
+fn check_alias_cycles(symbol_table: &SymbolTable) {
+    for alias in symbol_table.get_objects().iter().filter(|o| o.is_alias()) {
+        let mut chain = vec![alias.get_name()];
+        let mut current = alias.get_name();
+        while let Some(target) = get_alias_target_name(symbol_table, current.as_str()) {
+            if chain.contains(&target) {
+                chain.push(target.clone());
+                let message = format!("Alias cycle detected: {}", chain.join(" -> "));
+                handle_result_error(MError::SemanticError(message));
+            }
+            chain.push(target.clone());
+            current = target;
+        }
+    }
+}
+
+// turns every reference to a declared alias into `ALIAS` so the emitters can tell it apart
+// from a class
+pub fn resolve_aliases(symbol_table: &mut SymbolTable) {
+    check_alias_cycles(symbol_table);
+    let aliases: Vec<String> = symbol_table
+        .get_objects()
+        .iter()
+        .filter(|o| o.is_alias())
+        .map(|o| o.get_name())
+        .collect();
+    let mark = |array_type: &ArrayType| match array_type {
+        ArrayType::CUSTOM(name) if aliases.contains(name) => ArrayType::ALIAS(name.clone()),
+        _ => array_type.clone(),
+    };
+    for file in symbol_table.files.iter_mut() {
+        for class in file.objects.iter_mut() {
+            for field in class.fields.iter_mut() {
+                field.field_type = field.field_type.map_elements(&mark);
+            }
+        }
+    }
+
+    // defaults and constraints apply to the type an alias stands for
+    let objects = symbol_table.get_objects();
+    for file in symbol_table.files.iter_mut() {
+        let file_name = file.get_name();
+        for class in file.objects.iter_mut() {
+            let class_name = class.get_name();
+            for field in class.fields.iter_mut() {
+                let value_type = get_inlined_type(&field.field_type, &objects);
+                let location = format!(
+                    "field `{}` of class `{}` ({})",
+                    field.name, class_name, file_name
+                );
+                check_field_values(field, &value_type, location.as_str());
+                if value_type != field.field_type {
+                    field.value_type = Some(value_type);
+                }
+            }
+        }
+    }
+}
+
+fn get_constraint_name(constraint: &Constraint) -> &'static str {
+    match constraint {
+        Constraint::MIN(_) => "min",
+        Constraint::MAX(_) => "max",
+        Constraint::LENGTH(_, _) => "length",
+        Constraint::PATTERN(_) => "pattern",
+        Constraint::NONEMPTY => "nonempty",
+    }
+}
+
+fn check_field_values(field: &Field, value_type: &FieldType, location: &str) {
+    for constraint in field.get_constraints().iter() {
+        if let Some(reason) = get_constraint_error(constraint, value_type) {
+            let message = format!(
+                "Invalid `@{}` on {}: {}",
+                get_constraint_name(constraint),
+                location,
+                reason
+            );
+            handle_result_error(MError::SemanticError(message));
+        }
+    }
+    if let Some(default) = field.get_default() {
+        if !literal_matches_type(&default, value_type) {
+            let message = format!(
+                "Default value {:?} does not match the type {:?} of {}.",
+                default, value_type, location
+            );
+            handle_result_error(MError::SemanticError(message));
+        }
+    }
+}
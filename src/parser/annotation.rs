@@ -0,0 +1,116 @@
+use crate::common::handle_result_error;
+use crate::common::MError;
+
+// `@name(arg, key = arg)` written before a class or field
+#[derive(Clone, Debug)]
+pub struct Annotation {
+    pub name: String,
+    pub args: Vec<AnnotationArg>,
+}
+
+#[derive(Clone, Debug)]
+pub struct AnnotationArg {
+    pub key: Option<String>,
+    pub value: String,
+}
+
+impl Annotation {
+    pub fn get_arg(&self, index: usize, key: &str) -> Option<String> {
+        match self.args.iter().find(|a| a.key.as_deref() == Some(key)) {
+            Some(arg) => Some(arg.value.clone()),
+            None => self
+                .args
+                .iter()
+                .filter(|a| a.key.is_none())
+                .nth(index)
+                .map(|a| a.value.clone()),
+        }
+    }
+}
+
+pub fn is_annotation(token: &str) -> bool {
+    token.starts_with('@')
+}
+
+pub fn parse_annotation(token: &str) -> Annotation {
+    let open_paren_index = match token.find('(') {
+        Some(i) => i,
+        None => {
+            return Annotation {
+                name: token[1..].to_string(),
+                args: Vec::new(),
+            }
+        }
+    };
+    if !token.ends_with(')') {
+        let mut message = "Expected annotation arguments to end with `)` but found ".to_string();
+        message.push_str(token);
+        handle_result_error(MError::ParseError(message));
+    }
+    let mut args = Vec::new();
+    for arg in split_arguments(&token[open_paren_index + 1..token.len() - 1]) {
+        let is_named = match arg.find('=') {
+            Some(i) => arg[..i].chars().all(|c| c.is_alphanumeric() || c == '_'),
+            None => false,
+        };
+        if is_named {
+            let equals_index = arg.find('=').unwrap();
+            args.push(AnnotationArg {
+                key: Some(arg[..equals_index].to_string()),
+                value: arg[equals_index + 1..].to_string(),
+            });
+        } else {
+            args.push(AnnotationArg {
+                key: None,
+                value: arg,
+            });
+        }
+    }
+    Annotation {
+        name: token[1..open_paren_index].to_string(),
+        args,
+    }
+}
+
+// splits on the commas that are not inside a string or a nested `[...]`
+pub fn split_arguments(content: &str) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    let mut depth = 0;
+    let mut escaped = false;
+    for c in content.chars() {
+        match quote {
+            Some(q) => {
+                if !escaped && c == q {
+                    quote = None;
+                }
+                escaped = !escaped && c == '\\';
+                current.push(c);
+            }
+            None => match c {
+                '"' | '\'' => {
+                    quote = Some(c);
+                    current.push(c);
+                }
+                '[' | '(' => {
+                    depth += 1;
+                    current.push(c);
+                }
+                ']' | ')' => {
+                    depth -= 1;
+                    current.push(c);
+                }
+                ',' if depth == 0 => {
+                    items.push(current.clone());
+                    current.clear();
+                }
+                _ => current.push(c),
+            },
+        }
+    }
+    if !current.is_empty() {
+        items.push(current);
+    }
+    items
+}
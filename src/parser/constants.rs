@@ -0,0 +1,193 @@
+use crate::common::handle_result_error;
+use crate::common::MError;
+use crate::models::{Constraint, FieldType, Literal};
+use crate::objects::Field;
+use crate::parser::{literal_matches_type, SchemaFile, SymbolTable};
+
+fn fail(message: String) -> ! {
+    handle_result_error(MError::SemanticError(message));
+    panic!()
+}
+
+// `literal` with every constant replaced by its value, looked up in the files visible from
+// `file` and followed through constants defined by other constants
+fn resolve_literal(
+    literal: &Literal,
+    file: &SchemaFile,
+    symbol_table: &SymbolTable,
+    chain: &mut Vec<String>,
+    location: &str,
+) -> Literal {
+    match literal {
+        Literal::CONSTANT(name) => {
+            if chain.contains(name) {
+                chain.push(name.clone());
+                fail(format!("Constant cycle detected: {}", chain.join(" -> ")));
+            }
+            let declaring_file = symbol_table.find_declaring_file(name.as_str()).filter(|f| {
+                f.objects
+                    .iter()
+                    .any(|o| o.is_const() && &o.get_name() == name)
+            });
+            let declaring_file = match declaring_file {
+                Some(f) if symbol_table.get_visible_names(file).contains(name) => f,
+                Some(f) => fail(format!(
+                    "Constant `{}` in {} ({}) is declared in {} which is not imported.",
+                    name,
+                    location,
+                    file.get_name(),
+                    f.get_name()
+                )),
+                None => fail(format!(
+                    "Unknown constant `{}` in {} ({}).",
+                    name,
+                    location,
+                    file.get_name()
+                )),
+            };
+            let constant = declaring_file
+                .objects
+                .iter()
+                .find(|o| &o.get_name() == name)
+                .unwrap();
+            let value = match constant.get_const_value() {
+                Some(v) => v,
+                None => fail(format!("Constant `{}` has no value.", name)),
+            };
+            chain.push(name.clone());
+            let resolved = resolve_literal(&value, declaring_file, symbol_table, chain, location);
+            chain.pop();
+            resolved
+        }
+        Literal::ARRAY(items) => Literal::ARRAY(
+            items
+                .iter()
+                .map(|i| resolve_literal(i, file, symbol_table, chain, location))
+                .collect(),
+        ),
+        _ => literal.clone(),
+    }
+}
+
+// a constant used where a length is expected, e.g. `@length(max = MAX_NAME_LEN)`
+fn resolve_length(
+    literal: &Literal,
+    file: &SchemaFile,
+    symbol_table: &SymbolTable,
+    location: &str,
+    min: i64,
+) -> Literal {
+    let resolved = resolve_literal(literal, file, symbol_table, &mut Vec::new(), location);
+    match resolved {
+        Literal::INTEGER(value) if value >= min => resolved,
+        _ => fail(format!(
+            "Length `{}` in {} ({}) must resolve to an integer of at least {}.",
+            get_literal_name(literal),
+            location,
+            file.get_name(),
+            min
+        )),
+    }
+}
+
+fn get_literal_name(literal: &Literal) -> String {
+    match literal {
+        Literal::CONSTANT(name) => name.clone(),
+        _ => format!("{:?}", literal),
+    }
+}
+
+fn with_length(field_type: &FieldType, length: usize) -> FieldType {
+    match field_type {
+        FieldType::FIXEDARRAY(array_type, _) => FieldType::FIXEDARRAY(array_type.clone(), length),
+        _ => field_type.clone(),
+    }
+}
+
+fn resolve_field(
+    field: &Field,
+    file: &SchemaFile,
+    symbol_table: &SymbolTable,
+    location: &str,
+) -> Field {
+    let mut field = field.clone();
+    if let Some(name) = field.get_length_constant() {
+        let length = resolve_length(&Literal::CONSTANT(name), file, symbol_table, location, 1);
+        if let Literal::INTEGER(length) = length {
+            field.field_type = with_length(&field.field_type, length as usize);
+            field.value_type = field
+                .value_type
+                .as_ref()
+                .map(|t| with_length(t, length as usize));
+        }
+    }
+    let resolve = |literal: &Literal| {
+        let resolved = resolve_literal(literal, file, symbol_table, &mut Vec::new(), location);
+        if !literal_matches_type(&resolved, &field.get_value_type()) {
+            fail(format!(
+                "`{}` in {} ({}) resolves to {:?} which does not match the type {:?}.",
+                get_literal_name(literal),
+                location,
+                file.get_name(),
+                resolved,
+                field.get_value_type()
+            ));
+        }
+        resolved
+    };
+    let default = field.get_default().map(|d| resolve(&d));
+    let constraints: Vec<Constraint> = field
+        .get_constraints()
+        .iter()
+        .map(|constraint| match constraint {
+            Constraint::MIN(value) => Constraint::MIN(resolve(value)),
+            Constraint::MAX(value) => Constraint::MAX(resolve(value)),
+            Constraint::LENGTH(min, max) => {
+                let bound = |b: &Option<Literal>| {
+                    b.as_ref()
+                        .map(|l| resolve_length(l, file, symbol_table, location, 0))
+                };
+                Constraint::LENGTH(bound(min), bound(max))
+            }
+            _ => constraint.clone(),
+        })
+        .collect();
+    field.default = default;
+    field.constraints = constraints;
+    field
+}
+
+// replaces every constant used in a default value, constraint or fixed array length with its
+// value, which can only be type-checked once all schema files are loaded
+pub fn resolve_constants(symbol_table: &mut SymbolTable) {
+    let lookup = symbol_table.clone();
+    let constants: Vec<String> = lookup
+        .get_objects()
+        .iter()
+        .filter(|o| o.is_const())
+        .map(|o| o.get_name())
+        .collect();
+    for (file_index, file) in symbol_table.files.iter_mut().enumerate() {
+        let lookup_file = &lookup.files[file_index];
+        for class in file.objects.iter_mut() {
+            let is_const = class.is_const();
+            let class_name = class.get_name();
+            for field in class.fields.iter_mut() {
+                let location = if is_const {
+                    format!("constant `{}`", class_name)
+                } else {
+                    format!("field `{}` of class `{}`", field.name, class_name)
+                };
+                for name in field.get_field_type().get_referenced_types() {
+                    if constants.contains(&name) {
+                        fail(format!(
+                            "Constant `{}` cannot be used as the type of {}.",
+                            name, location
+                        ));
+                    }
+                }
+                *field = resolve_field(field, lookup_file, &lookup, location.as_str());
+            }
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use crate::common::{handle_result_error, MError};
+use crate::models::{Access, ClassKind, Constraint, Literal, Severity};
+use crate::objects::{Class, Field};
+use crate::parser::{SchemaFile, SymbolTable};
+use colored::Colorize;
+
+// every rule with the severity it has unless `--lint` says otherwise
+pub const LINT_RULES: [(&str, Severity); 6] = [
+    ("class-case", Severity::WARN),
+    ("field-case", Severity::WARN),
+    ("explicit-access", Severity::ALLOW),
+    ("empty-class", Severity::WARN),
+    ("case-duplicate", Severity::WARN),
+    ("unused-class", Severity::WARN),
+];
+
+struct Finding {
+    rule: &'static str,
+    line: usize,
+    message: String,
+}
+
+fn get_severity(rule: &str, levels: &[(String, Severity)]) -> Severity {
+    if let Some((_r, severity)) = levels.iter().find(|(r, _s)| r == rule) {
+        return severity.clone();
+    }
+    LINT_RULES
+        .iter()
+        .find(|(r, _s)| *r == rule)
+        .map(|(_r, s)| s.clone())
+        .unwrap_or(Severity::ALLOW)
+}
+
+fn is_pascal_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase()) && !name.contains('_')
+}
+
+fn is_camel_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase()) && !name.contains('_')
+}
+
+fn get_constant_names(literal: &Literal, names: &mut Vec<String>) {
+    match literal {
+        Literal::CONSTANT(name) => names.push(name.clone()),
+        Literal::ARRAY(items) => items.iter().for_each(|i| get_constant_names(i, names)),
+        _ => (),
+    }
+}
+
+// names of the classes, aliases and constants a field depends on
+fn get_field_references(field: &Field) -> Vec<String> {
+    let mut names = field.get_field_type().get_referenced_types();
+    names.extend(field.get_length_constant());
+    if let Some(default) = field.get_default() {
+        get_constant_names(&default, &mut names);
+    }
+    for constraint in field.get_constraints().iter() {
+        match constraint {
+            Constraint::MIN(value) | Constraint::MAX(value) => {
+                get_constant_names(value, &mut names)
+            }
+            Constraint::LENGTH(min, max) => {
+                for bound in min.iter().chain(max.iter()) {
+                    get_constant_names(bound, &mut names);
+                }
+            }
+            _ => (),
+        }
+    }
+    names
+}
+
+fn lint_class(class: &Class, findings: &mut Vec<Finding>) {
+    let name = class.get_name();
+    if class.kind != ClassKind::CONST && !is_pascal_case(name.as_str()) {
+        findings.push(Finding {
+            rule: "class-case",
+            line: class.line,
+            message: format!("`{}` should be PascalCase", name),
+        });
+    }
+    if class.get_access() == Access::UNDEFINED {
+        findings.push(Finding {
+            rule: "explicit-access",
+            line: class.line,
+            message: format!("`{}` has neither `pub` nor `priv`", name),
+        });
+    }
+    // aliases and constants keep their value in a field of their own
+    if !matches!(
+        class.kind,
+        ClassKind::CLASS | ClassKind::INTERFACE | ClassKind::UNION
+    ) {
+        return;
+    }
+    if class.fields.is_empty() && class.kind != ClassKind::UNION {
+        findings.push(Finding {
+            rule: "empty-class",
+            line: class.line,
+            message: format!("`{}` declares no fields", name),
+        });
+    }
+    for (i, field) in class.fields.iter().enumerate() {
+        if !is_camel_case(field.name.as_str()) {
+            findings.push(Finding {
+                rule: "field-case",
+                line: field.line,
+                message: format!("field `{}` of `{}` should be camelCase", field.name, name),
+            });
+        }
+        if field.get_access() == Access::UNDEFINED && !class.is_union() {
+            findings.push(Finding {
+                rule: "explicit-access",
+                line: field.line,
+                message: format!(
+                    "field `{}` of `{}` has neither `pub` nor `priv`",
+                    field.name, name
+                ),
+            });
+        }
+        // `userId` and `user_id` both become the same name once converted to a language's case
+        let folded = field.name.to_lowercase().replace('_', "");
+        if let Some(other) = class.fields[..i]
+            .iter()
+            .find(|f| f.name != field.name && f.name.to_lowercase().replace('_', "") == folded)
+        {
+            findings.push(Finding {
+                rule: "case-duplicate",
+                line: field.line,
+                message: format!(
+                    "field `{}` of `{}` only differs from `{}` (line {}) by case",
+                    field.name, name, other.name, other.line
+                ),
+            });
+        }
+    }
+}
+
+// `// allow(field-case, empty-class)` silences rules for the class or field on the same line,
+// or for the first one declared after it when the comment is on a line of its own
+fn get_allowed_rules(file: &SchemaFile) -> Vec<(usize, String)> {
+    let name = file.get_name();
+    let mut item_lines: Vec<usize> = Vec::new();
+    for class in file.objects.iter() {
+        item_lines.push(class.line);
+        item_lines.extend(class.fields.iter().map(|f| f.line));
+    }
+    item_lines.sort_unstable();
+
+    let mut allowed = Vec::new();
+    for comment in file.comments.iter() {
+        let line = &comment.line;
+        let rules = match comment
+            .text
+            .strip_prefix("//")
+            .filter(|t| !t.starts_with('/'))
+            .and_then(|t| t.trim().strip_prefix("allow("))
+            .and_then(|t| t.strip_suffix(')'))
+        {
+            Some(r) => r,
+            None => continue,
+        };
+        let target = if comment.is_trailing {
+            Some(*line)
+        } else {
+            item_lines.iter().copied().find(|l| l > line)
+        };
+        for rule in rules.split(',').map(|r| r.trim()).filter(|r| !r.is_empty()) {
+            if !LINT_RULES.iter().any(|(r, _s)| *r == rule) {
+                let message = format!("Unknown lint rule `{}` in {}:{}.", rule, name, line);
+                handle_result_error(MError::SemanticError(message));
+            }
+            if let Some(target) = target {
+                allowed.push((target, rule.to_string()));
+            }
+        }
+    }
+    allowed
+}
+
+// Reports style issues that do not stop the mapping. Runs before aliases and constants are
+// resolved so that every use of them is still visible.
+pub fn lint_schema_files(symbol_table: &SymbolTable, levels: &[(String, Severity)]) {
+    let objects = symbol_table.get_objects();
+    let mut errors = 0;
+    for file in symbol_table.files.iter() {
+        let mut findings = Vec::new();
+        for class in file.objects.iter() {
+            lint_class(class, &mut findings);
+        }
+        for class in file.objects.iter() {
+            let is_used = objects.iter().any(|o| {
+                o.get_name() != class.get_name()
+                    && (o.get_implements().contains(&class.get_name())
+                        || o.fields
+                            .iter()
+                            .any(|f| get_field_references(f).contains(&class.get_name())))
+            });
+            if !is_used {
+                findings.push(Finding {
+                    rule: "unused-class",
+                    line: class.line,
+                    message: format!("`{}` is not used by any other class", class.get_name()),
+                });
+            }
+        }
+        if findings.is_empty() {
+            continue;
+        }
+
+        let allowed = get_allowed_rules(file);
+        findings.sort_by_key(|f| f.line);
+        for finding in findings.iter() {
+            if allowed
+                .iter()
+                .any(|(line, rule)| *line == finding.line && rule == finding.rule)
+            {
+                continue;
+            }
+            let label = match get_severity(finding.rule, levels) {
+                Severity::ALLOW => continue,
+                Severity::WARN => "warning".yellow(),
+                Severity::ERROR => {
+                    errors += 1;
+                    "error".red()
+                }
+            };
+            println!(
+                "{}[{}] {}:{}: {}",
+                label,
+                finding.rule,
+                file.get_name(),
+                finding.line,
+                finding.message
+            );
+        }
+    }
+    if errors > 0 {
+        let message = format!("{} lint error(s) found.", errors);
+        handle_result_error(MError::SemanticError(message));
+    }
+}
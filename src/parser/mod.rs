@@ -1,3 +1,19 @@
+mod aliases;
+mod annotation;
+mod constants;
+mod lint;
 mod parser;
+mod schema;
+mod symbol_table;
+mod type_graph;
+mod validation;
 
+pub use aliases::*;
+pub use annotation::*;
+pub use constants::*;
+pub use lint::*;
 pub use parser::*;
+pub use schema::*;
+pub use symbol_table::*;
+pub use type_graph::*;
+pub use validation::*;
@@ -1,7 +1,11 @@
 use crate::common::handle_result_error;
+use crate::common::is_doc_comment;
 use crate::common::MError;
-use crate::models::{Access, ArrayType, FieldType, Language, ParseState};
+use crate::models::{
+    Access, ArrayType, ClassKind, Constraint, FieldType, Language, Literal, ParseState,
+};
 use crate::objects::{Class, Field};
+use crate::parser::{is_annotation, parse_annotation, split_arguments, Annotation};
 
 #[derive(Clone, Debug)]
 pub struct Parser {
@@ -11,7 +15,16 @@ pub struct Parser {
     pub current_class: Option<Class>,
     pub current_field_name: Option<String>,
     pub current_languages: Option<Vec<Language>>,
+    // `targets [ rs, ts ]` used by every class without a header of its own
+    pub default_languages: Option<Vec<Language>>,
     pub current_field_access: Access,
+    pub current_doc: Vec<String>,
+    pub current_annotations: Vec<Annotation>,
+    pub imports: Vec<String>,
+    pub namespace: Option<String>,
+    // line of each token, empty when the tokens were not read from a file
+    pub lines: Vec<usize>,
+    pub current_field_line: usize,
 }
 
 impl Parser {
@@ -24,14 +37,37 @@ impl Parser {
             current_class: None,
             current_field_name: None,
             current_languages: None,
+            default_languages: None,
             current_field_access: Access::UNDEFINED,
+            current_doc: Vec::new(),
+            current_annotations: Vec::new(),
+            imports: Vec::new(),
+            namespace: None,
+            lines: Vec::new(),
+            current_field_line: 0,
         }
     }
 
     fn add_class(&mut self, class: &Class) {
+        if class.languages.is_empty() {
+            let message = format!(
+                "`{}` is not generated for any language. Check its `[ ... ]` header and the \
+                 file-level `targets` list.",
+                class.get_name()
+            );
+            handle_result_error(MError::ParseError(message));
+        }
         self.objects.push(class.clone());
     }
 
+    pub fn set_lines(&mut self, lines: Vec<usize>) {
+        self.lines = lines;
+    }
+
+    fn get_current_line(&self) -> usize {
+        self.lines.get(self.index).copied().unwrap_or(0)
+    }
+
     fn get_current_class(&self) -> Option<Class> {
         self.current_class.clone()
     }
@@ -48,6 +84,10 @@ impl Parser {
         self.objects.clone()
     }
 
+    pub fn get_imports(&self) -> Vec<String> {
+        self.imports.clone()
+    }
+
     fn set_current_class(&mut self, class: &Class) {
         self.current_class = Some(class.clone());
     }
@@ -64,8 +104,106 @@ impl Parser {
         self.current_field_name = None;
     }
 
+    // collects a `///` token for the next class or field, returns false for other tokens
+    fn add_doc_comment(&mut self, token: &str) -> bool {
+        if !is_doc_comment(token) {
+            return false;
+        }
+        self.current_doc.push(token[3..].to_string());
+        self.index += 1;
+        true
+    }
+
+    fn take_current_doc(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.current_doc)
+    }
+
+    // collects an `@name(...)` token for the next class or field, returns false for other tokens
+    fn add_annotation(&mut self, token: &str) -> bool {
+        if !is_annotation(token) {
+            return false;
+        }
+        self.current_annotations.push(parse_annotation(token));
+        self.index += 1;
+        true
+    }
+
+    fn take_current_annotations(&mut self) -> Vec<Annotation> {
+        std::mem::take(&mut self.current_annotations)
+    }
+
+    fn apply_class_annotations(&mut self, class: &mut Class) {
+        for annotation in self.take_current_annotations() {
+            if annotation.name == "name" {
+                let owner = format!("class `{}`", class.get_name());
+                for (language, name) in parse_renames(&annotation, owner.as_str()) {
+                    class.add_rename(language, name.as_str());
+                }
+                continue;
+            }
+            if annotation.name == "deprecated" {
+                let owner = format!("class `{}`", class.get_name());
+                class.set_deprecated(parse_deprecation_note(&annotation, owner.as_str()).as_str());
+                continue;
+            }
+            if let Some(language) = parse_language(annotation.name.as_str()) {
+                let owner = format!("class `{}`", class.get_name());
+                for attribute in parse_attributes(&annotation, &class.languages, owner.as_str()) {
+                    class.add_attribute(language.clone(), attribute.as_str());
+                }
+                continue;
+            }
+            let message = format!(
+                "Unknown annotation `@{}` on class `{}`.",
+                annotation.name,
+                class.get_name()
+            );
+            handle_result_error(MError::ParseError(message));
+        }
+    }
+
+    fn apply_field_annotations(&mut self, field: &mut Field) {
+        let languages = self.current_languages.clone().unwrap_or_default();
+        for annotation in self.take_current_annotations() {
+            match annotation.name.as_str() {
+                "min" | "max" | "length" | "pattern" | "nonempty" => {
+                    field.add_constraint(parse_constraint(&annotation, field, &languages))
+                }
+                "rename" => {
+                    let owner = format!("field `{}`", field.get_name());
+                    for (language, name) in parse_renames(&annotation, owner.as_str()) {
+                        field.add_rename(language, name.as_str());
+                    }
+                }
+                "deprecated" => {
+                    let owner = format!("field `{}`", field.get_name());
+                    field.set_deprecated(
+                        parse_deprecation_note(&annotation, owner.as_str()).as_str(),
+                    );
+                }
+                name if parse_language(name).is_some() => {
+                    let language = parse_language(name).unwrap();
+                    let owner = format!("field `{}`", field.get_name());
+                    for attribute in parse_attributes(&annotation, &languages, owner.as_str()) {
+                        field.add_attribute(language.clone(), attribute.as_str());
+                    }
+                }
+                _ => {
+                    let message = format!(
+                        "Unknown annotation `@{}` on field `{}`.",
+                        annotation.name,
+                        field.get_name()
+                    );
+                    handle_result_error(MError::ParseError(message));
+                }
+            }
+        }
+    }
+
     pub fn parse(&mut self, content: &[String]) {
-        for _n in self.index..content.len() {
+        // a handler may only switch state without taking a token, e.g. for a class without a
+        // header, so the loop runs until every token is consumed rather than once per token
+        while self.index < content.len() {
             match self.parse_state {
                 ParseState::FILES => self.add_language(content),
                 ParseState::CLASS => self.handle_class(content),
@@ -73,35 +211,128 @@ impl Parser {
                 ParseState::FieldN => self.handle_field_n(content),
             }
         }
+        if let Some(class) = self.get_current_class() {
+            let message = format!(
+                "Reached the end of the file before `}}` closed class `{}`.",
+                class.get_name()
+            );
+            handle_result_error(MError::ParseError(message));
+        }
+        if !matches!(self.parse_state, ParseState::FILES) {
+            let message = "Reached the end of the file in the middle of a declaration.".to_string();
+            handle_result_error(MError::ParseError(message));
+        }
     }
 
     fn add_language(&mut self, tokens: &[String]) {
         if self.check_if_reached_end(tokens.len()) {
             return;
         }
-        let mut file_related_tokens: Vec<Language> = Vec::new();
 
+        if self.add_doc_comment(tokens.get(self.index).unwrap()) {
+            return;
+        }
+        if tokens.get(self.index).unwrap().as_str() == "import" {
+            self.handle_import(tokens);
+            return;
+        }
+        if matches!(
+            tokens.get(self.index).unwrap().as_str(),
+            "package" | "namespace"
+        ) {
+            self.handle_namespace(tokens);
+            return;
+        }
+        if tokens.get(self.index).unwrap().as_str() == "targets" {
+            self.handle_targets(tokens);
+            return;
+        }
         if tokens.get(self.index).unwrap().as_str() != "[" {
+            // classes without a header of their own use the file-level targets
+            if let Some(default_languages) = self.default_languages.clone() {
+                self.current_languages = Some(default_languages);
+                self.parse_state = ParseState::CLASS;
+                return;
+            }
             let mut message = "Expected object to start with `[` but found ".to_string();
             let first_token = tokens.get(self.index).unwrap().as_str();
             message.push_str(first_token);
             handle_result_error(MError::ParseError(message));
         }
 
+        let (mut languages, added, removed) = self.parse_header(tokens);
+        if !added.is_empty() || !removed.is_empty() {
+            if !languages.is_empty() {
+                let message = "A `[ ... ]` header either lists its languages or adjusts the \
+                     file-level targets with `+` and `-`, not both."
+                    .to_string();
+                handle_result_error(MError::ParseError(message));
+            }
+            languages = match self.default_languages.clone() {
+                Some(l) => l,
+                None => {
+                    let message = "`+` and `-` in a `[ ... ]` header adjust the file-level \
+                         `targets` list but none was declared."
+                        .to_string();
+                    handle_result_error(MError::ParseError(message));
+                    panic!()
+                }
+            };
+            languages.extend(added);
+            languages.retain(|l| !removed.contains(l));
+        }
+        languages.sort_unstable();
+        languages.dedup();
+        self.current_languages = Some(languages);
+        self.parse_state = ParseState::CLASS;
+    }
+
+    // `targets [ rs, ts, java ]` once, before the first class
+    fn handle_targets(&mut self, tokens: &[String]) {
+        if self.default_languages.is_some() || !self.objects.is_empty() {
+            let message = "`targets` must be declared once, before any class.".to_string();
+            handle_result_error(MError::ParseError(message));
+        }
+        self.index += 1;
+        if tokens.get(self.index).map(|t| t.as_str()) != Some("[") {
+            let message = "Expected a `[ ... ]` language list after `targets`.".to_string();
+            handle_result_error(MError::ParseError(message));
+        }
+        let (mut languages, added, removed) = self.parse_header(tokens);
+        if !added.is_empty() || !removed.is_empty() {
+            let message = "`targets` lists languages without `+` or `-`.".to_string();
+            handle_result_error(MError::ParseError(message));
+        }
+        languages.sort_unstable();
+        languages.dedup();
+        self.default_languages = Some(languages);
+    }
+
+    // the listed, `+` added and `-` removed languages of a `[ ... ]` header
+    fn parse_header(&mut self, tokens: &[String]) -> (Vec<Language>, Vec<Language>, Vec<Language>) {
+        let mut languages: Vec<Language> = Vec::new();
+        let mut added: Vec<Language> = Vec::new();
+        let mut removed: Vec<Language> = Vec::new();
         self.index += 1;
         for i in self.index..tokens.len() {
             let mut token = tokens.get(i).unwrap().clone();
             token.retain(|c| c != ',');
-            match token.as_str() {
-                "rs" => file_related_tokens.push(Language::RUST),
-                "c" => file_related_tokens.push(Language::C),
-                "java" => file_related_tokens.push(Language::JAVA),
-                "ts" => file_related_tokens.push(Language::TYPESCRIPT),
-                "]" => {
-                    self.index += 1;
-                    break;
-                }
-                _ => {
+            if token == "]" {
+                self.index += 1;
+                break;
+            }
+            if token.is_empty() {
+                self.index += 1;
+                continue;
+            }
+            let (list, name) = match (token.strip_prefix('+'), token.strip_prefix('-')) {
+                (Some(name), _) => (&mut added, name),
+                (_, Some(name)) => (&mut removed, name),
+                _ => (&mut languages, token.as_str()),
+            };
+            match parse_language(name) {
+                Some(language) => list.push(language),
+                None => {
                     let mut message = "Unknown language token found. \
                      Expected either `rs`, `ts`, `c`, or `java` but found "
                         .to_string();
@@ -111,10 +342,65 @@ impl Parser {
             }
             self.index += 1;
         }
-        file_related_tokens.sort_unstable();
-        file_related_tokens.dedup();
-        self.current_languages = Some(file_related_tokens);
-        self.parse_state = ParseState::CLASS;
+        (languages, added, removed)
+    }
+
+    // `import "address.txt"` with an optional trailing `;`
+    fn handle_import(&mut self, tokens: &[String]) {
+        self.index += 1;
+        let mut token = match tokens.get(self.index) {
+            Some(t) => t.clone(),
+            None => {
+                let message = "Expected a quoted file name after `import`.".to_string();
+                handle_result_error(MError::ParseError(message));
+                panic!()
+            }
+        };
+        if token.ends_with(';') {
+            token.truncate(token.len() - 1);
+        }
+        match parse_literal(token.as_str()) {
+            Some(Literal::STRING(path)) => self.imports.push(path),
+            _ => {
+                let mut message =
+                    "Expected a quoted file name after `import` but found ".to_string();
+                message.push_str(token.as_str());
+                handle_result_error(MError::ParseError(message));
+            }
+        }
+        self.index += 1;
+    }
+
+    // `package com.acme.users;` or `namespace com.acme.users;` before the first class
+    fn handle_namespace(&mut self, tokens: &[String]) {
+        if self.namespace.is_some() || !self.objects.is_empty() {
+            let message =
+                "A `package` or `namespace` must be declared once, before any class.".to_string();
+            handle_result_error(MError::ParseError(message));
+        }
+        self.index += 1;
+        let mut token = match tokens.get(self.index) {
+            Some(t) => t.clone(),
+            None => {
+                let message = "Expected a name after `package` or `namespace`.".to_string();
+                handle_result_error(MError::ParseError(message));
+                panic!()
+            }
+        };
+        if token.ends_with(';') {
+            token.truncate(token.len() - 1);
+        }
+        let is_valid = token.split('.').all(|segment| {
+            segment.starts_with(|c: char| c.is_alphabetic() || c == '_')
+                && segment.chars().all(|c| c.is_alphanumeric() || c == '_')
+        });
+        if !is_valid {
+            let mut message = "Invalid package name ".to_string();
+            message.push_str(token.as_str());
+            handle_result_error(MError::ParseError(message));
+        }
+        self.namespace = Some(token);
+        self.index += 1;
     }
 
     fn handle_class(&mut self, tokens: &[String]) {
@@ -130,6 +416,10 @@ impl Parser {
             }
         };
 
+        if self.add_doc_comment(token) || self.add_annotation(token) {
+            return;
+        }
+
         let mut access_given = false;
         let class_access = match token.as_str() {
             "pub" => {
@@ -156,19 +446,225 @@ impl Parser {
             };
         }
 
-        let class = match self.get_current_class() {
+        let mut class_kind = ClassKind::CLASS;
+        if token.as_str() == "interface" {
+            self.index += 1;
+            class_kind = ClassKind::INTERFACE;
+            token = match tokens.get(self.index) {
+                Some(t) => t,
+                None => {
+                    let message = "Expected an interface name after `interface`.".to_string();
+                    handle_result_error(MError::ParseError(message));
+                    panic!()
+                }
+            };
+        }
+
+        if token.as_str() == "type" {
+            self.handle_alias(tokens, class_access);
+            return;
+        }
+        if token.as_str() == "const" {
+            self.handle_const(tokens, class_access);
+            return;
+        }
+
+        if token.as_str() == "union" {
+            self.index += 1;
+            class_kind = ClassKind::UNION;
+            token = match tokens.get(self.index) {
+                Some(t) => t,
+                None => {
+                    let message = "Expected a union name after `union`.".to_string();
+                    handle_result_error(MError::ParseError(message));
+                    panic!()
+                }
+            };
+        }
+
+        let (token, discriminator) = split_discriminator(token);
+        if discriminator.is_some() && class_kind != ClassKind::UNION {
+            let mut message = "Only unions can declare a discriminator but found ".to_string();
+            message.push_str(token.as_str());
+            handle_result_error(MError::ParseError(message));
+        }
+        let (class_name, type_params) = split_type_parameters(token.as_str());
+        if class_kind != ClassKind::CLASS && !type_params.is_empty() {
+            let mut message = match class_kind {
+                ClassKind::UNION => "Union `".to_string(),
+                _ => "Interface `".to_string(),
+            };
+            message.push_str(class_name.as_str());
+            message.push_str("` cannot declare type parameters.");
+            handle_result_error(MError::ParseError(message));
+        }
+
+        let mut class = match self.get_current_class() {
             Some(c) => c,
             None => Class::new(
-                token.to_string(),
+                class_name,
                 self.current_languages.as_ref().unwrap(),
                 class_access,
             ),
         };
-        self.set_current_class(&class);
+        class.set_kind(class_kind);
+        class.set_line(self.get_current_line());
+        if let Some(discriminator) = discriminator {
+            class.set_discriminator(discriminator.as_str());
+        }
+        class.set_doc(&self.take_current_doc());
+        class.set_namespace(self.namespace.clone());
+        self.apply_class_annotations(&mut class);
+        for type_param in type_params.iter() {
+            class.add_type_parameter(type_param);
+        }
         self.index += 1;
+        if let Some(t) = tokens.get(self.index) {
+            if t.as_str() == "implements" {
+                self.index += 1;
+                self.handle_implements(tokens, &mut class);
+            }
+        }
+        self.set_current_class(&class);
         self.parse_state = ParseState::FieldT;
     }
 
+    // `pub type UserId = String` with an optional trailing `;`
+    fn handle_alias(&mut self, tokens: &[String], access: Access) {
+        let name = tokens.get(self.index + 1).cloned().unwrap_or_default();
+        let mut target = tokens.get(self.index + 3).cloned().unwrap_or_default();
+        if target.ends_with(';') {
+            target.truncate(target.len() - 1);
+        }
+        if tokens.get(self.index + 2).map(|t| t.as_str()) != Some("=") || target.is_empty() {
+            let mut message = "Expected `type Name = Type` for alias ".to_string();
+            message.push_str(name.as_str());
+            handle_result_error(MError::ParseError(message));
+        }
+        let mut class = Class::new(name, self.current_languages.as_ref().unwrap(), access);
+        class.set_kind(ClassKind::ALIAS);
+        class.set_line(self.get_current_line());
+        class.set_doc(&self.take_current_doc());
+        class.set_namespace(self.namespace.clone());
+        self.apply_class_annotations(&mut class);
+        let field_type = parse_field_type(target.as_str());
+        if field_type.to_array_type().is_none() {
+            let message = format!(
+                "Alias `{}` must stand for a single type such as `String` or a class, not `{}`.",
+                class.get_name(),
+                target
+            );
+            handle_result_error(MError::ParseError(message));
+        }
+        class.add_field(&Field::new(
+            "value".to_string(),
+            field_type,
+            Access::UNDEFINED,
+        ));
+        self.add_class(&class);
+        self.index += 4;
+        self.parse_state = ParseState::FILES;
+    }
+
+    // `pub const MAX_NAME_LEN: int = 64` with an optional trailing `;`
+    fn handle_const(&mut self, tokens: &[String], access: Access) {
+        let name = tokens.get(self.index + 1).cloned().unwrap_or_default();
+        let type_token = tokens.get(self.index + 2).cloned().unwrap_or_default();
+        let mut value = tokens.get(self.index + 4).cloned().unwrap_or_default();
+        if value.ends_with(';') {
+            value.truncate(value.len() - 1);
+        }
+        let name = match name.strip_suffix(':') {
+            Some(n) if is_identifier(n) => n.to_string(),
+            _ => String::new(),
+        };
+        if name.is_empty()
+            || tokens.get(self.index + 3).map(|t| t.as_str()) != Some("=")
+            || value.is_empty()
+        {
+            let message = "Expected `const NAME: Type = value` for constant".to_string();
+            handle_result_error(MError::ParseError(message));
+        }
+        let mut class = Class::new(name, self.current_languages.as_ref().unwrap(), access);
+        class.set_kind(ClassKind::CONST);
+        class.set_line(self.get_current_line());
+        class.set_doc(&self.take_current_doc());
+        class.set_namespace(self.namespace.clone());
+        self.apply_class_annotations(&mut class);
+        let field_type = parse_field_type(type_token.as_str());
+        let is_scalar = field_type.is_numeric()
+            || matches!(
+                field_type,
+                FieldType::STRING | FieldType::BOOL | FieldType::CHAR
+            );
+        if !is_scalar {
+            let message = format!(
+                "Constant `{}` must be a number, String, bool or char, not `{}`.",
+                class.get_name(),
+                type_token
+            );
+            handle_result_error(MError::ParseError(message));
+        }
+        let literal = match parse_literal(value.as_str()) {
+            Some(l) if literal_matches_type(&l, &field_type) => l,
+            _ => {
+                let message = format!(
+                    "Value `{}` does not match the type {:?} of constant `{}`.",
+                    value,
+                    field_type,
+                    class.get_name()
+                );
+                handle_result_error(MError::ParseError(message));
+                panic!()
+            }
+        };
+        let mut field = Field::new("value".to_string(), field_type, Access::UNDEFINED);
+        field.set_default(literal);
+        class.add_field(&field);
+        self.add_class(&class);
+        self.index += 5;
+        self.parse_state = ParseState::FILES;
+    }
+
+    fn handle_implements(&mut self, tokens: &[String], class: &mut Class) {
+        if class.is_interface() || class.is_union() {
+            let mut message = if class.is_union() {
+                "Union `".to_string()
+            } else {
+                "Interface `".to_string()
+            };
+            message.push_str(class.get_name().as_str());
+            message.push_str("` cannot implement other interfaces.");
+            handle_result_error(MError::ParseError(message));
+        }
+        loop {
+            let mut token = match tokens.get(self.index) {
+                Some(t) => t.clone(),
+                None => {
+                    let message =
+                        "Expected `{` after the list of implemented interfaces.".to_string();
+                    handle_result_error(MError::ParseError(message));
+                    panic!()
+                }
+            };
+            if token == "{" {
+                break;
+            }
+            token.retain(|c| c != ',');
+            if !token.is_empty() {
+                class.add_implemented_interface(token.as_str());
+            }
+            self.index += 1;
+        }
+        if class.get_implements().is_empty() {
+            let mut message =
+                "Expected at least one interface after `implements` for class `".to_string();
+            message.push_str(class.get_name().as_str());
+            message.push('`');
+            handle_result_error(MError::ParseError(message));
+        }
+    }
+
     fn handle_field_t(&mut self, tokens: &[String]) {
         let mut token = match tokens.get(self.index) {
             Some(t) => t,
@@ -178,6 +674,19 @@ impl Parser {
                 panic!()
             }
         };
+        if self.add_doc_comment(token) || self.add_annotation(token) {
+            return;
+        }
+        // `pub Marker { }` declares no fields at all
+        if token == "}" {
+            if let Some(class) = self.get_current_class().filter(|c| c.fields.is_empty()) {
+                self.add_class(&class);
+                self.reset_current_class();
+                self.parse_state = ParseState::FILES;
+                self.index += 1;
+                return;
+            }
+        }
         match token.as_str() {
             "priv" => {
                 self.current_field_access = Access::PRIVATE;
@@ -203,6 +712,7 @@ impl Parser {
             let mut token_cpy = token.clone();
             token_cpy.truncate(token_cpy.len() - 1);
             self.set_current_field(&token_cpy);
+            self.current_field_line = self.get_current_line();
             self.parse_state = ParseState::FieldN;
         } else if token != "{" {
             let mut message =
@@ -231,7 +741,13 @@ impl Parser {
                 panic!()
             }
         };
-        let is_last_field = token.contains(',');
+        let mut is_last_field = token.ends_with(',');
+
+        // a doc comment after the last field has nothing to document
+        if is_doc_comment(token) {
+            self.index += 1;
+            return;
+        }
 
         if token == "{" {
             let message = "Cannot have `{` as a field type.".to_string();
@@ -249,48 +765,7 @@ impl Parser {
             if is_last_field {
                 token_cpy.truncate(token_cpy.len() - 1);
             }
-            let field_type: FieldType;
-            if token_cpy == "String" {
-                field_type = FieldType::STRING;
-            } else if token_cpy == "int" {
-                field_type = FieldType::INTEGER;
-            } else if token_cpy == "bool" {
-                field_type = FieldType::BOOL;
-            } else if token_cpy == "double" {
-                field_type = FieldType::DOUBLE;
-            } else if token_cpy == "char" {
-                field_type = FieldType::CHAR;
-            } else if token_cpy.contains('[') && token_cpy.contains(']') {
-                let open_bracket_index = token_cpy.find('[').unwrap();
-                let closed_bracket_index = token_cpy.find(']').unwrap();
-                let array_type = token_cpy
-                    .get(open_bracket_index + 1..closed_bracket_index)
-                    .unwrap();
-                match array_type {
-                    "String" => field_type = FieldType::ARRAY(ArrayType::STRING),
-                    "int" => field_type = FieldType::ARRAY(ArrayType::INTEGER),
-                    "char" => field_type = FieldType::ARRAY(ArrayType::CHAR),
-                    "bool" => field_type = FieldType::ARRAY(ArrayType::BOOL),
-                    "double" => field_type = FieldType::ARRAY(ArrayType::DOUBLE),
-                    _ => field_type = FieldType::ARRAY(ArrayType::CUSTOM(String::from(array_type))),
-                }
-            } else if token_cpy.contains('<') && token_cpy.contains('>') {
-                let open_bracket_index = token_cpy.find('<').unwrap();
-                let closed_bracket_index = token_cpy.find('>').unwrap();
-                let array_type = token_cpy
-                    .get(open_bracket_index + 1..closed_bracket_index)
-                    .unwrap();
-                match array_type {
-                    "String" => field_type = FieldType::LIST(ArrayType::STRING),
-                    "int" => field_type = FieldType::LIST(ArrayType::INTEGER),
-                    "char" => field_type = FieldType::LIST(ArrayType::CHAR),
-                    "bool" => field_type = FieldType::LIST(ArrayType::BOOL),
-                    "double" => field_type = FieldType::LIST(ArrayType::DOUBLE),
-                    _ => field_type = FieldType::LIST(ArrayType::CUSTOM(String::from(array_type))),
-                }
-            } else {
-                field_type = FieldType::CUSTOM(token_cpy);
-            }
+            let field_type = parse_field_type(token_cpy.as_str());
             match self.get_current_field() {
                 Some(_s) => (),
                 None => {
@@ -299,11 +774,20 @@ impl Parser {
                     panic!()
                 }
             }
-            let field = Field::new(
+            let mut field = Field::new(
                 self.get_current_field().unwrap(),
                 field_type,
                 self.get_current_filed_access(),
             );
+            if let Some(name) = parse_length_constant(token_cpy.as_str()) {
+                field.set_length_constant(name.as_str());
+            }
+            field.set_doc(&self.take_current_doc());
+            field.set_line(self.current_field_line);
+            if !is_last_field && tokens.get(self.index + 1).map(|t| t.as_str()) == Some("=") {
+                is_last_field = self.handle_default_value(tokens, &mut field);
+            }
+            self.apply_field_annotations(&mut field);
             current_class.add_field(&field);
             self.set_current_class(&current_class);
             if is_last_field {
@@ -314,11 +798,510 @@ impl Parser {
         self.index += 1;
     }
 
+    // parses the literal after `=` into the field's default value and returns whether it
+    // was followed by a comma
+    fn handle_default_value(&mut self, tokens: &[String], field: &mut Field) -> bool {
+        self.index += 2;
+        let mut token = match tokens.get(self.index) {
+            Some(t) => t.clone(),
+            None => {
+                let mut message = "Expected a default value after `=` for field ".to_string();
+                message.push_str(field.get_name().as_str());
+                handle_result_error(MError::ParseError(message));
+                panic!()
+            }
+        };
+        let has_comma = token.ends_with(',');
+        if has_comma {
+            token.truncate(token.len() - 1);
+        }
+        let literal = match parse_literal(token.as_str()) {
+            Some(l) => l,
+            None => {
+                let message = format!(
+                    "Invalid default value `{}` for field `{}`.",
+                    token,
+                    field.get_name()
+                );
+                handle_result_error(MError::ParseError(message));
+                panic!()
+            }
+        };
+        // a type naming another type is only known once every file is loaded
+        let field_type = field.get_field_type();
+        if field_type.get_referenced_types().is_empty()
+            && !literal_matches_type(&literal, &field_type)
+        {
+            let message = format!(
+                "Default value `{}` does not match the type {:?} of field `{}`.",
+                token,
+                field.get_field_type(),
+                field.get_name()
+            );
+            handle_result_error(MError::ParseError(message));
+        }
+        field.set_default(literal);
+        has_comma
+    }
+
     fn check_if_reached_end(&self, size: usize) -> bool {
         self.index == size
     }
 }
 
+fn parse_literal(token: &str) -> Option<Literal> {
+    let quoted =
+        |quote: char| token.len() >= 2 && token.starts_with(quote) && token.ends_with(quote);
+    if token == "true" || token == "false" {
+        Some(Literal::BOOL(token == "true"))
+    } else if quoted('"') {
+        Some(Literal::STRING(token[1..token.len() - 1].to_string()))
+    } else if quoted('\'') {
+        Some(Literal::CHAR(token[1..token.len() - 1].to_string()))
+    } else if token.starts_with('[') && token.ends_with(']') {
+        let mut items = Vec::new();
+        for item in split_arguments(&token[1..token.len() - 1]).iter() {
+            items.push(parse_literal(item.as_str())?);
+        }
+        Some(Literal::ARRAY(items))
+    } else if let Ok(value) = token.parse::<i64>() {
+        Some(Literal::INTEGER(value))
+    } else if let Ok(value) = token.parse::<f64>() {
+        Some(Literal::DOUBLE(value))
+    } else if is_identifier(token) {
+        Some(Literal::CONSTANT(token.to_string()))
+    } else {
+        None
+    }
+}
+
+fn is_identifier(token: &str) -> bool {
+    token.starts_with(|c: char| c.is_alphabetic() || c == '_')
+        && token.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+pub fn parse_language(token: &str) -> Option<Language> {
+    match token {
+        "rs" => Some(Language::RUST),
+        "c" => Some(Language::C),
+        "java" => Some(Language::JAVA),
+        "ts" => Some(Language::TYPESCRIPT),
+        _ => None,
+    }
+}
+
+// `@rename(rs = "kind", java = "type")` and `@name(c = "usr_t")` map a language to the
+// identifier emitted for it
+fn parse_renames(annotation: &Annotation, owner: &str) -> Vec<(Language, String)> {
+    let invalid = |reason: String| -> ! {
+        let message = format!("Invalid `@{}` on {}: {}", annotation.name, owner, reason);
+        handle_result_error(MError::ParseError(message));
+        panic!()
+    };
+    if annotation.args.is_empty() {
+        invalid("expected at least one `language = \"name\"` argument.".to_string());
+    }
+    let mut renames: Vec<(Language, String)> = Vec::new();
+    for arg in annotation.args.iter() {
+        let key = match arg.key.as_deref() {
+            Some(k) => k,
+            None => invalid(format!(
+                "expected `language = \"name\"` but found `{}`.",
+                arg.value
+            )),
+        };
+        let language = match parse_language(key) {
+            Some(l) => l,
+            None => invalid(format!(
+                "unknown language `{}`. Expected either `rs`, `ts`, `c`, or `java`.",
+                key
+            )),
+        };
+        let name = match parse_literal(arg.value.as_str()) {
+            Some(Literal::STRING(n)) => n,
+            _ => invalid(format!("expected a quoted name for `{}`.", key)),
+        };
+        if !is_identifier(name.as_str()) {
+            invalid(format!("`{}` is not a valid identifier.", name));
+        }
+        if renames.iter().any(|(l, _n)| *l == language) {
+            invalid(format!("`{}` is renamed more than once.", key));
+        }
+        renames.push((language, name));
+    }
+    renames
+}
+
+// `@deprecated` or `@deprecated("use fullName")`
+fn parse_deprecation_note(annotation: &Annotation, owner: &str) -> String {
+    if annotation.args.is_empty() {
+        return String::new();
+    }
+    match annotation
+        .get_arg(0, "note")
+        .as_deref()
+        .and_then(parse_literal)
+    {
+        Some(Literal::STRING(note)) if annotation.args.len() == 1 => note,
+        _ => {
+            let message = format!(
+                "Invalid `@deprecated` on {}: expected at most one quoted note.",
+                owner
+            );
+            handle_result_error(MError::ParseError(message));
+            panic!()
+        }
+    }
+}
+
+// `@rs("#[serde(skip)]")` holds target code that is emitted verbatim for that language only
+fn parse_attributes(annotation: &Annotation, languages: &[Language], owner: &str) -> Vec<String> {
+    let invalid = |reason: &str| -> ! {
+        let message = format!("Invalid `@{}` on {}: {}", annotation.name, owner, reason);
+        handle_result_error(MError::ParseError(message));
+        panic!()
+    };
+    if !languages.contains(&parse_language(annotation.name.as_str()).unwrap()) {
+        invalid("the language is not in the header so the attribute would never be emitted.");
+    }
+    if annotation.args.is_empty() {
+        invalid("expected at least one quoted attribute.");
+    }
+    let mut attributes = Vec::new();
+    for arg in annotation.args.iter() {
+        match (arg.key.as_ref(), parse_literal(arg.value.as_str())) {
+            (None, Some(Literal::STRING(attribute))) if !attribute.trim().is_empty() => {
+                attributes.push(attribute.replace("\\\"", "\"").replace("\\\\", "\\"))
+            }
+            _ => invalid("expected quoted attributes such as `\"#[serde(skip)]\"`."),
+        }
+    }
+    attributes
+}
+
+fn invalid_annotation(annotation: &Annotation, field: &Field, reason: &str) -> ! {
+    let message = format!(
+        "Invalid `@{}` on field `{}`: {}",
+        annotation.name,
+        field.get_name(),
+        reason
+    );
+    handle_result_error(MError::ParseError(message));
+    panic!()
+}
+
+// Constraints on a field whose type names another type, e.g. an alias, are only checked once
+// that type is known, see `resolve_aliases`.
+fn parse_constraint(annotation: &Annotation, field: &Field, languages: &[Language]) -> Constraint {
+    let field_type = field.get_field_type();
+    let invalid = |reason: &str| -> ! { invalid_annotation(annotation, field, reason) };
+    let constraint = match annotation.name.as_str() {
+        "min" | "max" => {
+            let bound = match annotation
+                .get_arg(0, "value")
+                .as_deref()
+                .and_then(parse_literal)
+            {
+                Some(l) => l,
+                None => invalid("expected a number matching the field type."),
+            };
+            if annotation.name == "min" {
+                Constraint::MIN(bound)
+            } else {
+                Constraint::MAX(bound)
+            }
+        }
+        "length" => {
+            let parse_bound = |arg: Option<String>| match arg {
+                Some(a) => match parse_literal(a.as_str()) {
+                    Some(Literal::INTEGER(v)) if v >= 0 => Some(Literal::INTEGER(v)),
+                    Some(Literal::CONSTANT(name)) => Some(Literal::CONSTANT(name)),
+                    _ => invalid("length bounds must be non-negative integers or constants."),
+                },
+                None => None,
+            };
+            let min = parse_bound(annotation.get_arg(0, "min"));
+            let max = parse_bound(annotation.get_arg(1, "max"));
+            if min.is_none() && max.is_none() {
+                invalid("expected a `min` and/or `max` length.");
+            }
+            Constraint::LENGTH(min, max)
+        }
+        "pattern" => match annotation
+            .get_arg(0, "regex")
+            .as_deref()
+            .and_then(parse_literal)
+        {
+            Some(Literal::STRING(pattern)) => {
+                if let Err(e) = regex::Regex::new(pattern.as_str()) {
+                    invalid(format!("the regular expression does not compile.\n{}", e).as_str());
+                }
+                if languages.contains(&Language::C) {
+                    if let Some(reason) = get_posix_pattern_error(pattern.as_str()) {
+                        invalid(reason);
+                    }
+                }
+                Constraint::PATTERN(pattern)
+            }
+            _ => invalid("expected a quoted regular expression."),
+        },
+        _ => Constraint::NONEMPTY,
+    };
+    if field_type.get_referenced_types().is_empty() {
+        if let Some(reason) = get_constraint_error(&constraint, &field_type) {
+            invalid(reason);
+        }
+    }
+    constraint
+}
+
+// C validates through POSIX extended regular expressions, which read `\d` as a plain `d` and
+// have no `(?...)` groups or lazy quantifiers
+fn get_posix_pattern_error(pattern: &str) -> Option<&'static str> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut in_bracket = false;
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => {
+                if let Some('d' | 'D' | 'w' | 'W' | 's' | 'S' | 'b' | 'B') = chars.get(i + 1) {
+                    return Some(
+                        "C uses POSIX regular expressions, write classes such as `\\d`, `\\w` \
+                         and `\\s` as `[0-9]`, `[[:alnum:]_]` and `[[:space:]]`.",
+                    );
+                }
+                i += 1;
+            }
+            '[' if !in_bracket => in_bracket = true,
+            ']' if in_bracket => in_bracket = false,
+            '(' if !in_bracket && chars.get(i + 1) == Some(&'?') => {
+                return Some("C uses POSIX regular expressions, which have no `(?...)` groups.");
+            }
+            '*' | '+' | '?' | '}' if !in_bracket && chars.get(i + 1) == Some(&'?') => {
+                return Some(
+                    "C uses POSIX regular expressions, which have no lazy quantifiers such as `*?`.",
+                );
+            }
+            _ => (),
+        }
+        i += 1;
+    }
+    None
+}
+
+// why `constraint` cannot apply to a field of `field_type`, if it cannot
+pub fn get_constraint_error(
+    constraint: &Constraint,
+    field_type: &FieldType,
+) -> Option<&'static str> {
+    let has_length = matches!(
+        field_type,
+        FieldType::STRING | FieldType::ARRAY(_) | FieldType::LIST(_)
+    );
+    match constraint {
+        Constraint::MIN(_) | Constraint::MAX(_) if !field_type.is_numeric() => {
+            Some("only numeric fields can have a minimum or maximum.")
+        }
+        Constraint::MIN(bound) | Constraint::MAX(bound)
+            if !literal_matches_type(bound, field_type) =>
+        {
+            Some("expected a number matching the field type.")
+        }
+        Constraint::LENGTH(_, _) if !has_length => {
+            Some("only strings, arrays and lists have a length.")
+        }
+        Constraint::PATTERN(_) if *field_type != FieldType::STRING => {
+            Some("only String fields can have a pattern.")
+        }
+        Constraint::NONEMPTY if !has_length => {
+            Some("only strings, arrays and lists can be required to be non-empty.")
+        }
+        _ => None,
+    }
+}
+
+// constants are only checked once their value is known, see `resolve_constants`
+pub fn literal_matches_type(literal: &Literal, field_type: &FieldType) -> bool {
+    match (field_type, literal) {
+        (_, Literal::CONSTANT(_)) => true,
+        (FieldType::DOUBLE | FieldType::FLOAT, Literal::INTEGER(_) | Literal::DOUBLE(_)) => true,
+        (_, Literal::INTEGER(value)) => match field_type.get_integer_range() {
+            Some((min, max)) => (min..=max).contains(&(*value as i128)),
+            None => false,
+        },
+        (FieldType::STRING, Literal::STRING(_)) => true,
+        (FieldType::CHAR, Literal::CHAR(c)) => c.chars().count() == 1 || c.starts_with('\\'),
+        (FieldType::BOOL, Literal::BOOL(_)) => true,
+        (FieldType::FIXEDARRAY(array_type, length), Literal::ARRAY(items)) => {
+            let item_type = array_type.to_field_type();
+            // a length of 0 stands for a `[T; NAME]` length that is not resolved yet
+            (items.len() == *length || *length == 0)
+                && items.iter().all(|i| literal_matches_type(i, &item_type))
+        }
+        (FieldType::ARRAY(array_type), Literal::ARRAY(items))
+        | (FieldType::LIST(array_type), Literal::ARRAY(items)) => {
+            let item_type = array_type.to_field_type();
+            items.iter().all(|i| literal_matches_type(i, &item_type))
+        }
+        _ => false,
+    }
+}
+
+fn parse_field_type(token: &str) -> FieldType {
+    let field_type: FieldType;
+    let primitive_type = parse_array_type(token);
+    if !matches!(primitive_type, ArrayType::CUSTOM(_)) {
+        field_type = primitive_type.to_field_type();
+    } else if token.contains('[') && token.contains(']') {
+        let open_bracket_index = token.find('[').unwrap();
+        let closed_bracket_index = token.find(']').unwrap();
+        let array_type = token
+            .get(open_bracket_index + 1..closed_bracket_index)
+            .unwrap();
+        field_type = match array_type.split_once(';') {
+            Some((element_type, length)) => match length.parse::<usize>() {
+                Ok(length) if length > 0 => {
+                    FieldType::FIXEDARRAY(parse_array_type(element_type), length)
+                }
+                _ if is_identifier(length) => {
+                    FieldType::FIXEDARRAY(parse_array_type(element_type), 0)
+                }
+                _ => {
+                    let mut message = "Expected a positive array length but found `".to_string();
+                    message.push_str(token);
+                    message.push('`');
+                    handle_result_error(MError::ParseError(message));
+                    panic!()
+                }
+            },
+            None => FieldType::ARRAY(parse_array_type(array_type)),
+        };
+    } else if token.contains('<') && token.ends_with('>') {
+        let open_bracket_index = token.find('<').unwrap();
+        let closed_bracket_index = token.len() - 1;
+        let generic_name = token.get(..open_bracket_index).unwrap();
+        let array_type = token
+            .get(open_bracket_index + 1..closed_bracket_index)
+            .unwrap();
+        if array_type.contains('<') {
+            let mut message = "Nested generic types are not supported but found `".to_string();
+            message.push_str(token);
+            message.push('`');
+            handle_result_error(MError::ParseError(message));
+        }
+        if generic_name.is_empty() || generic_name == "List" {
+            field_type = FieldType::LIST(parse_array_type(array_type));
+        } else {
+            let args = array_type.split(',').map(parse_array_type).collect();
+            field_type = FieldType::GENERIC(generic_name.to_string(), args);
+        }
+    } else if token.starts_with('(') && token.ends_with(')') {
+        let items: Vec<ArrayType> = token[1..token.len() - 1]
+            .split(',')
+            .filter(|i| !i.is_empty())
+            .map(parse_array_type)
+            .collect();
+        if items.len() < 2 {
+            let mut message = "Expected a tuple of at least two types but found `".to_string();
+            message.push_str(token);
+            message.push('`');
+            handle_result_error(MError::ParseError(message));
+        }
+        field_type = FieldType::TUPLE(items);
+    } else {
+        field_type = FieldType::CUSTOM(token.to_string());
+    }
+    field_type
+}
+
+// `NAME` in a `[T; NAME]` field type
+fn parse_length_constant(token: &str) -> Option<String> {
+    let open_bracket_index = token.find('[')?;
+    let closed_bracket_index = token.find(']')?;
+    let (_element_type, length) = token
+        .get(open_bracket_index + 1..closed_bracket_index)?
+        .split_once(';')?;
+    if is_identifier(length) {
+        Some(length.to_string())
+    } else {
+        None
+    }
+}
+
+fn parse_array_type(token: &str) -> ArrayType {
+    match token {
+        "String" => ArrayType::STRING,
+        "int" | "i32" => ArrayType::INTEGER,
+        "char" => ArrayType::CHAR,
+        "bool" => ArrayType::BOOL,
+        "double" | "f64" => ArrayType::DOUBLE,
+        "i8" => ArrayType::I8,
+        "i16" => ArrayType::I16,
+        "i64" => ArrayType::I64,
+        "u8" => ArrayType::U8,
+        "u16" => ArrayType::U16,
+        "u32" => ArrayType::U32,
+        "u64" => ArrayType::U64,
+        "float" | "f32" => ArrayType::FLOAT,
+        "datetime" => ArrayType::DATETIME,
+        "date" => ArrayType::DATE,
+        "duration" => ArrayType::DURATION,
+        "uuid" => ArrayType::UUID,
+        "decimal" => ArrayType::DECIMAL,
+        "bytes" => ArrayType::BYTES,
+        _ => ArrayType::CUSTOM(String::from(token)),
+    }
+}
+
+// `Shape(kind)` is split into the union name and its discriminator
+fn split_discriminator(token: &str) -> (String, Option<String>) {
+    let open_paren_index = match token.find('(') {
+        Some(i) => i,
+        None => return (token.to_string(), None),
+    };
+    let discriminator = token[open_paren_index + 1..]
+        .strip_suffix(')')
+        .unwrap_or_default();
+    let is_valid = discriminator.starts_with(|c: char| c.is_alphabetic() || c == '_')
+        && discriminator
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_');
+    if !is_valid {
+        let mut message =
+            "Expected a discriminator field name such as `Shape(kind)` but found ".to_string();
+        message.push_str(token);
+        handle_result_error(MError::ParseError(message));
+    }
+    (
+        token[..open_paren_index].to_string(),
+        Some(discriminator.to_string()),
+    )
+}
+
+// `Page<T, U>` is split into the class name and its type parameters
+fn split_type_parameters(token: &str) -> (String, Vec<String>) {
+    let open_bracket_index = match token.find('<') {
+        Some(i) => i,
+        None => return (token.to_string(), Vec::new()),
+    };
+    if !token.ends_with('>') {
+        let mut message = "Expected type parameters to end with `>` but found ".to_string();
+        message.push_str(token);
+        handle_result_error(MError::ParseError(message));
+    }
+    let params: Vec<String> = token[open_bracket_index + 1..token.len() - 1]
+        .split(',')
+        .filter(|p| !p.is_empty())
+        .map(|p| p.to_string())
+        .collect();
+    if params.is_empty() {
+        let mut message = "Expected at least one type parameter in ".to_string();
+        message.push_str(token);
+        handle_result_error(MError::ParseError(message));
+    }
+    (token[..open_bracket_index].to_string(), params)
+}
+
 pub fn check_if_brackets_align(buf: &[String]) {
     let mut stack = Vec::new();
 
@@ -362,3 +1345,174 @@ pub fn check_if_brackets_align(buf: &[String]) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::tokenize;
+
+    fn parse_schema(content: &str) -> Vec<Class> {
+        let (tokens, lines): (Vec<String>, Vec<usize>) =
+            tokenize(content, false).into_iter().unzip();
+        check_if_brackets_align(&tokens);
+        let mut parser = Parser::new();
+        parser.set_lines(lines);
+        parser.parse(&tokens);
+        parser.get_objects()
+    }
+
+    #[test]
+    fn deduplicates_repeated_languages() {
+        let objects = parse_schema("[ java, rs, java, ts, rs ]\npub A { a: int }");
+        assert_eq!(
+            objects[0].languages,
+            vec![Language::JAVA, Language::TYPESCRIPT, Language::RUST]
+        );
+    }
+
+    #[test]
+    fn deduplicates_languages_added_to_the_targets() {
+        let objects =
+            parse_schema("targets [ java, rs, java ]\nA { a: int }\n[ +java ]\nB { b: int }");
+        assert_eq!(objects[0].languages, vec![Language::JAVA, Language::RUST]);
+        assert_eq!(objects[1].languages, vec![Language::JAVA, Language::RUST]);
+    }
+
+    #[test]
+    fn parses_every_class_without_a_header() {
+        let content = "targets [ rs ]\nA { a: int }\nB { b: int }\nC { c: int }\n\
+                       D { d: int }\nE { e: int }\nF { f: int }";
+        let objects = parse_schema(content);
+        let names: Vec<String> = objects.iter().map(|o| o.get_name()).collect();
+        assert_eq!(names, vec!["A", "B", "C", "D", "E", "F"]);
+        for (line, class) in objects.iter().enumerate() {
+            assert_eq!(class.languages, vec![Language::RUST]);
+            assert_eq!(class.fields.len(), 1);
+            assert_eq!(class.line, line + 2);
+        }
+    }
+
+    #[test]
+    fn header_replaces_the_targets() {
+        let objects = parse_schema("targets [ rs ]\nA { a: int }\n[ ts, java ]\nB { b: int }");
+        assert_eq!(objects[0].languages, vec![Language::RUST]);
+        assert_eq!(
+            objects[1].languages,
+            vec![Language::JAVA, Language::TYPESCRIPT]
+        );
+    }
+
+    #[test]
+    fn adjusts_the_targets_without_spaces() {
+        let objects = parse_schema(
+            "targets [rs,ts,c]\nA { a: int }\n[-c]\nB { b: int }\n[+java,-ts]\nC { c: int }",
+        );
+        assert_eq!(
+            objects[0].languages,
+            vec![Language::TYPESCRIPT, Language::C, Language::RUST]
+        );
+        assert_eq!(
+            objects[1].languages,
+            vec![Language::TYPESCRIPT, Language::RUST]
+        );
+        assert_eq!(
+            objects[2].languages,
+            vec![Language::JAVA, Language::C, Language::RUST]
+        );
+    }
+
+    #[test]
+    fn accepts_posix_patterns() {
+        assert_eq!(get_posix_pattern_error("^[0-9]{5}(-[[:alnum:]]+)?$"), None);
+        assert_eq!(get_posix_pattern_error("^a\\.b[*?]$"), None);
+    }
+
+    #[test]
+    fn rejects_patterns_posix_reads_differently() {
+        for pattern in ["^\\d+$", "\\bword", "(?:a|b)", "a(?=b)", "a*?", "a{2,3}?"] {
+            assert!(get_posix_pattern_error(pattern).is_some(), "{}", pattern);
+        }
+    }
+
+    #[test]
+    fn parses_default_values() {
+        let content =
+            "[ rs ]\npub User {\n    pub age: int = 5,\n    pub name: String = \"a b\",\n    \
+                       pub flag: bool = true,\n    pub ratio: double = 0.5,\n    \
+                       pub tags: List<String> = [\"x\", \"y\"],\n    pub plain: int\n}";
+        let objects = parse_schema(content);
+        let defaults: Vec<Option<Literal>> =
+            objects[0].fields.iter().map(|f| f.get_default()).collect();
+        assert_eq!(
+            defaults,
+            vec![
+                Some(Literal::INTEGER(5)),
+                Some(Literal::STRING("a b".to_string())),
+                Some(Literal::BOOL(true)),
+                Some(Literal::DOUBLE(0.5)),
+                Some(Literal::ARRAY(vec![
+                    Literal::STRING("x".to_string()),
+                    Literal::STRING("y".to_string())
+                ])),
+                None,
+            ]
+        );
+        let lines: Vec<usize> = objects[0].fields.iter().map(|f| f.line).collect();
+        assert_eq!(lines, vec![3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn parses_constraints() {
+        let content = "[ rs ]\npub User {\n    @min(0) @max(150)\n    pub age: int,\n    \
+                       @length(1, 64) @pattern(\"^[a-z]+$\")\n    pub name: String,\n    \
+                       @nonempty\n    pub tags: List<String>\n}";
+        let objects = parse_schema(content);
+        let class = &objects[0];
+        assert_eq!(
+            class.get_field("age").unwrap().get_constraints(),
+            vec![
+                Constraint::MIN(Literal::INTEGER(0)),
+                Constraint::MAX(Literal::INTEGER(150))
+            ]
+        );
+        assert_eq!(
+            class.get_field("name").unwrap().get_constraints(),
+            vec![
+                Constraint::LENGTH(Some(Literal::INTEGER(1)), Some(Literal::INTEGER(64))),
+                Constraint::PATTERN("^[a-z]+$".to_string())
+            ]
+        );
+        assert_eq!(
+            class.get_field("tags").unwrap().get_constraints(),
+            vec![Constraint::NONEMPTY]
+        );
+    }
+
+    #[test]
+    fn parses_name_overrides() {
+        let content = "[ rs, c ]\n@name(c = \"person_t\")\npub User {\n    \
+                       @rename(rs = \"ident\")\n    pub id: String\n}";
+        let objects = parse_schema(content);
+        assert_eq!(objects[0].get_name_for(&Language::C), "person_t");
+        assert_eq!(objects[0].get_name_for(&Language::RUST), "User");
+        assert_eq!(
+            objects[0].get_field("id").unwrap().renames,
+            vec![(Language::RUST, "ident".to_string())]
+        );
+    }
+
+    #[test]
+    fn parses_deprecations() {
+        let content = "[ rs ]\n@deprecated(\"use Person\")\npub User {\n    \
+                       @deprecated\n    pub name: String,\n    pub age: int\n}";
+        let objects = parse_schema(content);
+        let class = &objects[0];
+        assert_eq!(class.deprecated, Some("use Person".to_string()));
+        // a bare `@deprecated` keeps an empty note
+        assert_eq!(
+            class.get_field("name").unwrap().deprecated,
+            Some(String::new())
+        );
+        assert_eq!(class.get_field("age").unwrap().deprecated, None);
+    }
+}
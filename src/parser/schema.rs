@@ -0,0 +1,173 @@
+use crate::common::{
+    get_file_buffer, handle_result_error, is_comment, is_doc_comment, open_file, MError,
+};
+use crate::objects::Class;
+use crate::parser::{check_if_brackets_align, Parser};
+use colored::Colorize;
+use std::path::{Path, PathBuf};
+use std::thread;
+
+// a parsed input file together with the files it imports
+#[derive(Clone, Debug)]
+pub struct SchemaFile {
+    pub path: PathBuf,
+    pub imports: Vec<PathBuf>,
+    pub objects: Vec<Class>,
+    // the `//` and `/* */` comments the parser skips, read for `// allow(...)` directives
+    pub comments: Vec<SchemaComment>,
+}
+
+#[derive(Clone, Debug)]
+pub struct SchemaComment {
+    pub text: String,
+    pub line: usize,
+    // written after code on the same line rather than on a line of its own
+    pub is_trailing: bool,
+}
+
+impl SchemaFile {
+    pub fn get_name(&self) -> String {
+        self.path.display().to_string()
+    }
+}
+
+fn ensure_input_is_text_file(file: &Path) -> bool {
+    file.extension().is_some_and(|e| e == "txt")
+}
+
+fn resolve_path(file: &Path, imported_from: Option<&Path>) -> PathBuf {
+    if !ensure_input_is_text_file(file) {
+        let mut message = "Input file must have a .txt extension to be analyzed: ".to_string();
+        message.push_str(file.display().to_string().as_str());
+        handle_result_error(MError::GenError(message));
+    }
+    match file.canonicalize() {
+        Ok(p) => p,
+        Err(_e) => {
+            let mut message = "Error opening file: ".to_string();
+            message.push_str(file.display().to_string().as_str());
+            if let Some(importer) = imported_from {
+                message.push_str(" (imported from ");
+                message.push_str(importer.display().to_string().as_str());
+                message.push(')');
+            }
+            handle_result_error(MError::GenError(message));
+            panic!()
+        }
+    }
+}
+
+fn parse_schema_file(path: PathBuf) -> SchemaFile {
+    let file_name = path.display().to_string();
+    let mut input_file = open_file(file_name.as_str());
+    let mut parser = Parser::new();
+    let mut comments: Vec<SchemaComment> = Vec::new();
+    let mut code: Vec<(String, usize)> = Vec::new();
+    for (token, line) in get_file_buffer(&mut input_file, file_name.as_str(), true) {
+        if is_comment(token.as_str()) && !is_doc_comment(token.as_str()) {
+            let is_trailing = code.last().is_some_and(|(_t, l)| *l == line);
+            comments.push(SchemaComment {
+                text: token,
+                line,
+                is_trailing,
+            });
+        } else {
+            code.push((token, line));
+        }
+    }
+    let (file_content, lines): (Vec<String>, Vec<usize>) = code.into_iter().unzip();
+    check_if_brackets_align(&file_content);
+    parser.set_lines(lines);
+    parser.parse(&file_content);
+
+    // imports are relative to the importing file
+    let directory = path.parent().unwrap_or_else(|| Path::new(""));
+    let imports = parser
+        .get_imports()
+        .iter()
+        .map(|i| resolve_path(&directory.join(i), Some(&path)))
+        .collect();
+    SchemaFile {
+        path,
+        imports,
+        objects: parser.get_objects(),
+        comments,
+    }
+}
+
+// Parses every input and everything they import, one thread per file. Each round parses the
+// files discovered by the previous one so a file is only ever parsed once.
+pub fn load_schema_files(inputs: &[String]) -> Vec<SchemaFile> {
+    let mut pending: Vec<PathBuf> = Vec::new();
+    for input in inputs.iter() {
+        let path = resolve_path(Path::new(input), None);
+        if !pending.contains(&path) {
+            pending.push(path);
+        }
+    }
+
+    let mut files: Vec<SchemaFile> = Vec::new();
+    while !pending.is_empty() {
+        let mut children = vec![];
+        for path in pending.drain(..) {
+            println!("{}{:?}", "Starting thread for ".cyan(), path);
+            children.push(thread::spawn(move || parse_schema_file(path)));
+        }
+        for child in children {
+            let file = match child.join() {
+                Ok(f) => f,
+                Err(_e) => {
+                    let message = "An error occurred while parsing a schema file.".to_string();
+                    handle_result_error(MError::GenError(message));
+                    panic!()
+                }
+            };
+            files.push(file);
+        }
+        for file in files.iter() {
+            for import in file.imports.iter() {
+                if !pending.contains(import) && !files.iter().any(|f| &f.path == import) {
+                    pending.push(import.clone());
+                }
+            }
+        }
+    }
+    files
+}
+
+pub fn check_import_cycles(files: &[SchemaFile]) {
+    let mut finished: Vec<PathBuf> = Vec::new();
+    for file in files.iter() {
+        let mut stack: Vec<PathBuf> = Vec::new();
+        visit_imports(file, files, &mut stack, &mut finished);
+    }
+}
+
+fn visit_imports(
+    file: &SchemaFile,
+    files: &[SchemaFile],
+    stack: &mut Vec<PathBuf>,
+    finished: &mut Vec<PathBuf>,
+) {
+    if finished.contains(&file.path) {
+        return;
+    }
+    if let Some(start) = stack.iter().position(|p| p == &file.path) {
+        let mut cycle: Vec<String> = stack[start..]
+            .iter()
+            .map(|p| p.display().to_string())
+            .collect();
+        cycle.push(file.get_name());
+        let mut message = "Import cycle detected: ".to_string();
+        message.push_str(cycle.join(" -> ").as_str());
+        handle_result_error(MError::ParseError(message));
+    }
+    stack.push(file.path.clone());
+    for import in file.imports.iter() {
+        if let Some(imported) = files.iter().find(|f| &f.path == import) {
+            visit_imports(imported, files, stack, finished);
+        }
+    }
+    stack.pop();
+    finished.push(file.path.clone());
+}
@@ -0,0 +1,40 @@
+use crate::objects::Class;
+use crate::parser::SchemaFile;
+
+// every class declared across all loaded schema files
+#[derive(Clone, Debug)]
+pub struct SymbolTable {
+    pub files: Vec<SchemaFile>,
+}
+
+impl SymbolTable {
+    pub fn new(files: Vec<SchemaFile>) -> SymbolTable {
+        SymbolTable { files }
+    }
+
+    pub fn get_files(&self) -> Vec<SchemaFile> {
+        self.files.clone()
+    }
+
+    // names declared in `file` or in any file it imports directly
+    pub fn get_visible_names(&self, file: &SchemaFile) -> Vec<String> {
+        let mut names: Vec<String> = file.objects.iter().map(|o| o.get_name()).collect();
+        for imported in self.files.iter().filter(|f| file.imports.contains(&f.path)) {
+            names.extend(imported.objects.iter().map(|o| o.get_name()));
+        }
+        names
+    }
+
+    pub fn find_declaring_file(&self, name: &str) -> Option<&SchemaFile> {
+        self.files
+            .iter()
+            .find(|f| f.objects.iter().any(|o| o.get_name() == name))
+    }
+
+    pub fn get_objects(&self) -> Vec<Class> {
+        self.files
+            .iter()
+            .flat_map(|f| f.objects.iter().cloned())
+            .collect()
+    }
+}
@@ -0,0 +1,171 @@
+use crate::models::{ArrayType, FieldType};
+use crate::objects::Class;
+use crate::parser::SymbolTable;
+use colored::Colorize;
+
+// Classes a field stores by value. Arrays and lists are already behind a pointer or `Vec`
+// but fixed-length arrays and tuples are stored inline, while a generic such as `Page<User>`
+// also holds `User` by value when `Page` has a `T` field.
+fn get_by_value_targets(
+    type_params: &[String],
+    field_type: &FieldType,
+    objects: &[Class],
+) -> Vec<String> {
+    match field_type {
+        FieldType::CUSTOM(name)
+        | FieldType::ALIAS(name)
+        | FieldType::FIXEDARRAY(ArrayType::CUSTOM(name) | ArrayType::ALIAS(name), _)
+            if !type_params.contains(name) =>
+        {
+            vec![name.clone()]
+        }
+        FieldType::TUPLE(items) => items
+            .iter()
+            .filter_map(|i| match i {
+                ArrayType::CUSTOM(name) | ArrayType::ALIAS(name) if !type_params.contains(name) => {
+                    Some(name.clone())
+                }
+                _ => None,
+            })
+            .collect(),
+        FieldType::GENERIC(name, args) => {
+            let mut targets = vec![name.clone()];
+            if let Some(generic_class) = objects.iter().find(|o| &o.get_name() == name) {
+                for (param, arg) in generic_class.get_type_parameters().iter().zip(args.iter()) {
+                    let holds_by_value = generic_class.fields.iter().any(|f| {
+                        !f.is_indirect() && f.get_field_type() == FieldType::CUSTOM(param.clone())
+                    });
+                    if let (true, FieldType::CUSTOM(arg_name)) =
+                        (holds_by_value, arg.to_field_type())
+                    {
+                        targets.push(arg_name);
+                    }
+                }
+            }
+            targets
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn get_edges(objects: &[Class]) -> Vec<(String, String)> {
+    let mut edges = Vec::new();
+    for class in objects.iter().filter(|c| !c.is_interface()) {
+        for field in class.fields.iter().filter(|f| !f.is_indirect()) {
+            let type_params = class.get_type_parameters();
+            for target in get_by_value_targets(&type_params, &field.get_field_type(), objects) {
+                edges.push((class.get_name(), target));
+            }
+        }
+    }
+    edges
+}
+
+fn is_reachable(from: &str, to: &str, edges: &[(String, String)]) -> bool {
+    let mut visited: Vec<&str> = Vec::new();
+    let mut stack = vec![from];
+    while let Some(current) = stack.pop() {
+        if current == to {
+            return true;
+        }
+        if visited.contains(&current) {
+            continue;
+        }
+        visited.push(current);
+        for (source, target) in edges.iter() {
+            if source == current {
+                stack.push(target.as_str());
+            }
+        }
+    }
+    false
+}
+
+// Marks every by-value field that leads back to its own class, e.g. `emergencyContact: User`
+// inside `User`, so it is emitted as `struct User*` in C and `Option<Box<User>>` in Rust.
+pub fn mark_recursive_fields(symbol_table: &mut SymbolTable) {
+    let objects = symbol_table.get_objects();
+    let edges = get_edges(&objects);
+    for file in symbol_table.files.iter_mut() {
+        for class in file.objects.iter_mut() {
+            if class.is_interface() {
+                continue;
+            }
+            let class_name = class.get_name();
+            let type_params = class.get_type_parameters();
+            for field in class.fields.iter_mut() {
+                let targets = get_by_value_targets(&type_params, &field.get_field_type(), &objects);
+                if targets
+                    .iter()
+                    .any(|t| is_reachable(t.as_str(), class_name.as_str(), &edges))
+                {
+                    print!(
+                        "{}",
+                        "Recursive by-value field, emitting it through a pointer: ".yellow()
+                    );
+                    println!("{}.{}", class_name, field.get_name());
+                    field.set_indirect(true);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::tokenize;
+    use crate::parser::Parser;
+
+    fn get_schema_edges(content: &str) -> Vec<(String, String)> {
+        let (tokens, lines): (Vec<String>, Vec<usize>) =
+            tokenize(content, false).into_iter().unzip();
+        let mut parser = Parser::new();
+        parser.set_lines(lines);
+        parser.parse(&tokens);
+        get_edges(&parser.get_objects())
+    }
+
+    fn edge(from: &str, to: &str) -> (String, String) {
+        (from.to_string(), to.to_string())
+    }
+
+    #[test]
+    fn follows_fields_stored_by_value() {
+        let edges = get_schema_edges(
+            "targets [ c ]\n\
+             A { b: B, c: [C; 2], d: (D, int) }\n\
+             B { list: List<A>, array: A[] }\n\
+             C { c: int }\nD { d: int }",
+        );
+        assert_eq!(edges, vec![edge("A", "B"), edge("A", "C"), edge("A", "D")]);
+    }
+
+    #[test]
+    fn follows_generics_into_their_by_value_parameters() {
+        let edges = get_schema_edges(
+            "targets [ c ]\n\
+             A { page: Page<B>, list: ListPage<B> }\n\
+             B { b: int }\n\
+             Page<T> { item: T }\n\
+             ListPage<T> { items: List<T> }",
+        );
+        assert_eq!(
+            edges,
+            vec![edge("A", "Page"), edge("A", "B"), edge("A", "ListPage")]
+        );
+    }
+
+    #[test]
+    fn finds_cycles_through_other_classes() {
+        let edges = vec![
+            edge("A", "B"),
+            edge("B", "C"),
+            edge("C", "A"),
+            edge("C", "D"),
+        ];
+        assert!(is_reachable("B", "A", &edges));
+        assert!(is_reachable("A", "A", &edges));
+        assert!(!is_reachable("D", "A", &edges));
+    }
+}
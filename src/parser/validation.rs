@@ -0,0 +1,360 @@
+use crate::common::handle_result_error;
+use crate::common::MError;
+use crate::models::{ArrayType, FieldType, Language};
+use crate::objects::Class;
+use crate::parser::{SchemaFile, SymbolTable};
+
+// a second `User` would overwrite the files generated for the first, and a second `age` field
+// would not compile in any language
+pub fn check_duplicate_names(schema_files: &[SchemaFile]) {
+    let mut declared: Vec<(String, String)> = Vec::new();
+    for file in schema_files.iter() {
+        for class in file.objects.iter() {
+            let location = format!("{}:{}", file.get_name(), class.line);
+            if let Some((_name, first)) = declared.iter().find(|(n, _l)| *n == class.get_name()) {
+                let message = format!(
+                    "`{}` is declared more than once, in {} and in {}.",
+                    class.get_name(),
+                    first,
+                    location
+                );
+                handle_result_error(MError::SemanticError(message));
+            }
+            declared.push((class.get_name(), location));
+
+            for (i, field) in class.fields.iter().enumerate() {
+                if let Some(first) = class.fields[..i].iter().find(|f| f.name == field.name) {
+                    let message = format!(
+                        "Field `{}` is declared more than once in class `{}`, in {}:{} and in {}:{}.",
+                        field.get_name(),
+                        class.get_name(),
+                        file.get_name(),
+                        first.line,
+                        file.get_name(),
+                        field.line
+                    );
+                    handle_result_error(MError::SemanticError(message));
+                }
+            }
+        }
+    }
+}
+
+pub fn check_interface_implementations(objects: &[Class]) {
+    for class in objects.iter() {
+        for interface_name in class.get_implements() {
+            let interface = match objects
+                .iter()
+                .find(|o| o.is_interface() && o.get_name() == interface_name)
+            {
+                Some(i) => i,
+                None => {
+                    let message = format!(
+                        "Class `{}` implements `{}` but no interface with that name was declared.",
+                        class.get_name(),
+                        interface_name
+                    );
+                    handle_result_error(MError::ParseError(message));
+                    panic!()
+                }
+            };
+
+            for field in interface.fields.iter() {
+                match class.get_field(field.name.as_str()) {
+                    Some(f) if f.get_field_type() == field.get_field_type() => (),
+                    Some(f) => {
+                        let message = format!(
+                            "Field `{}` of class `{}` has type {:?} but interface `{}` requires {:?}.",
+                            f.get_name(),
+                            class.get_name(),
+                            f.get_field_type(),
+                            interface_name,
+                            field.get_field_type()
+                        );
+                        handle_result_error(MError::ParseError(message));
+                    }
+                    None => {
+                        let message = format!(
+                            "Class `{}` implements `{}` but does not declare the required field `{}`.",
+                            class.get_name(),
+                            interface_name,
+                            field.get_name()
+                        );
+                        handle_result_error(MError::ParseError(message));
+                    }
+                }
+            }
+
+            for language in class.languages.iter() {
+                if *language != Language::C && !interface.languages.contains(language) {
+                    let message = format!(
+                        "Class `{}` is generated for {:?} but interface `{}` is not.",
+                        class.get_name(),
+                        language,
+                        interface_name
+                    );
+                    handle_result_error(MError::ParseError(message));
+                }
+            }
+        }
+    }
+}
+
+pub fn check_generic_arguments(objects: &[Class]) {
+    for class in objects.iter() {
+        for field in class.fields.iter() {
+            let (name, arg_count) = match field.get_field_type() {
+                FieldType::GENERIC(name, args) => (name, args.len()),
+                FieldType::CUSTOM(name)
+                | FieldType::ARRAY(ArrayType::CUSTOM(name))
+                | FieldType::FIXEDARRAY(ArrayType::CUSTOM(name), _)
+                | FieldType::LIST(ArrayType::CUSTOM(name)) => (name, 0),
+                _ => continue,
+            };
+            let generic_class = match objects.iter().find(|o| o.get_name() == name) {
+                Some(c) => c,
+                None => continue,
+            };
+            let expected = generic_class.get_type_parameters().len();
+            if expected != arg_count {
+                let message = format!(
+                    "Field `{}` of class `{}` uses `{}` with {} type argument(s) but `{}` expects {}.",
+                    field.get_name(),
+                    class.get_name(),
+                    name,
+                    arg_count,
+                    generic_class.get_generic_name(),
+                    expected
+                );
+                handle_result_error(MError::ParseError(message));
+            }
+        }
+    }
+}
+
+// every variant of a union must carry a plain class, which Java seals into the union and
+// therefore has to live in the same package
+pub fn check_union_variants(objects: &[Class]) {
+    for union in objects.iter().filter(|o| o.is_union()) {
+        if union.fields.is_empty() {
+            let message = format!(
+                "Union `{}` must declare at least one variant.",
+                union.get_name()
+            );
+            handle_result_error(MError::SemanticError(message));
+        }
+        for field in union.fields.iter() {
+            let invalid = |reason: String| {
+                let message = format!(
+                    "Variant `{}` of union `{}` {}",
+                    field.get_name(),
+                    union.get_name(),
+                    reason
+                );
+                handle_result_error(MError::SemanticError(message));
+            };
+            if field.get_default().is_some() || !field.get_constraints().is_empty() {
+                invalid("cannot have a default value or constraints.".to_string());
+            }
+            let payload = match field.get_field_type() {
+                FieldType::CUSTOM(name) => objects.iter().find(|o| o.get_name() == name),
+                _ => None,
+            };
+            let payload = match payload {
+                Some(p) if !p.is_interface() && !p.is_union() && !p.is_generic() => p,
+                _ => {
+                    invalid(
+                        "must hold a class that is not an interface, union or generic.".to_string(),
+                    );
+                    continue;
+                }
+            };
+            if payload.get_namespace() != union.get_namespace() {
+                invalid(format!(
+                    "holds `{}` which must be declared in the same package as the union.",
+                    payload.get_name()
+                ));
+            }
+            if payload
+                .get_field(union.get_discriminator().as_str())
+                .is_some()
+            {
+                invalid(format!(
+                    "holds `{}` which already has a field named after the discriminator `{}`.",
+                    payload.get_name(),
+                    union.get_discriminator()
+                ));
+            }
+        }
+    }
+}
+
+const BUILT_IN_TYPES: [&str; 22] = [
+    "String", "int", "bool", "double", "char", "i8", "i16", "i32", "i64", "u8", "u16", "u32",
+    "u64", "float", "f32", "f64", "datetime", "date", "duration", "uuid", "decimal", "bytes",
+];
+
+pub fn check_unresolved_types(symbol_table: &SymbolTable) {
+    for file in symbol_table.files.iter() {
+        let visible_names = symbol_table.get_visible_names(file);
+        for class in file.objects.iter() {
+            let mut known_names = visible_names.clone();
+            known_names.extend(class.get_type_parameters());
+
+            let mut references: Vec<(String, String)> = Vec::new();
+            for field in class.fields.iter() {
+                for name in field.get_field_type().get_referenced_types() {
+                    references.push((name, format!("field `{}`", field.get_name())));
+                }
+            }
+            for interface in class.get_implements() {
+                references.push((interface, "its implements clause".to_string()));
+            }
+
+            for (name, location) in references {
+                if known_names.contains(&name) {
+                    continue;
+                }
+                let mut message = format!(
+                    "Unknown type `{}` in {} of class `{}` ({}).",
+                    name,
+                    location,
+                    class.get_name(),
+                    file.get_name()
+                );
+                match symbol_table.find_declaring_file(name.as_str()) {
+                    Some(declaring_file) => {
+                        message.push_str(" It is declared in ");
+                        message.push_str(declaring_file.get_name().as_str());
+                        message.push_str(" which is not imported.");
+                    }
+                    None => {
+                        let mut candidates = known_names.clone();
+                        candidates.extend(BUILT_IN_TYPES.iter().map(|t| t.to_string()));
+                        if let Some(suggestion) = find_closest_name(name.as_str(), &candidates) {
+                            message.push_str(" Did you mean `");
+                            message.push_str(suggestion.as_str());
+                            message.push_str("`?");
+                        }
+                    }
+                }
+                handle_result_error(MError::SemanticError(message));
+            }
+        }
+    }
+}
+
+fn find_closest_name(name: &str, candidates: &[String]) -> Option<String> {
+    let lowercase_name = name.to_lowercase();
+    candidates
+        .iter()
+        .map(|c| {
+            (
+                edit_distance(lowercase_name.as_str(), c.to_lowercase().as_str()),
+                c,
+            )
+        })
+        .filter(|(distance, c)| *distance <= (c.len() / 3).max(2))
+        .min_by_key(|(distance, _c)| *distance)
+        .map(|(_distance, c)| c.clone())
+}
+
+// Levenshtein distance between `a` and `b`
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b_chars.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b_chars.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::tokenize;
+    use crate::parser::Parser;
+    use std::path::PathBuf;
+
+    fn get_schema_file(path: &str, imports: &[&str], content: &str) -> SchemaFile {
+        let tokens: Vec<String> = tokenize(content, false)
+            .into_iter()
+            .map(|(t, _l)| t)
+            .collect();
+        let mut parser = Parser::new();
+        parser.parse(&tokens);
+        SchemaFile {
+            path: PathBuf::from(path),
+            imports: imports.iter().map(PathBuf::from).collect(),
+            objects: parser.get_objects(),
+            comments: Vec::new(),
+        }
+    }
+
+    fn get_schema_files() -> Vec<SchemaFile> {
+        let common = get_schema_file(
+            "/schemas/common.txt",
+            &[],
+            "targets [ rs ]\npub interface Named { name: String }\n\
+             pub Page<T> { items: List<T>, total: int }\npub Address { name: String }",
+        );
+        let users = get_schema_file(
+            "/schemas/users.txt",
+            &["/schemas/common.txt"],
+            "targets [ rs ]\npub type UserId = String\n\
+             pub User implements Named { id: UserId, name: String, home: Address, \
+             pages: Page<Address>, friends: List<User> }",
+        );
+        vec![common, users]
+    }
+
+    #[test]
+    fn accepts_field_names_repeated_across_classes() {
+        // `name` is declared in `Named`, `Address` and `User`, each only once
+        check_duplicate_names(&get_schema_files());
+    }
+
+    #[test]
+    fn accepts_imported_and_local_types() {
+        check_unresolved_types(&SymbolTable::new(get_schema_files()));
+    }
+
+    #[test]
+    fn accepts_type_parameters_of_the_class() {
+        let file = get_schema_file(
+            "/schemas/pair.txt",
+            &[],
+            "targets [ rs ]\npub Pair<K, V> { key: K, value: V, rest: List<V> }",
+        );
+        check_unresolved_types(&SymbolTable::new(vec![file]));
+    }
+
+    #[test]
+    fn suggests_the_closest_name() {
+        let candidates: Vec<String> = ["String", "Address", "User"]
+            .iter()
+            .map(|c| c.to_string())
+            .collect();
+        assert_eq!(
+            find_closest_name("Strng", &candidates),
+            Some("String".to_string())
+        );
+        assert_eq!(
+            find_closest_name("adress", &candidates),
+            Some("Address".to_string())
+        );
+        assert_eq!(find_closest_name("Invoice", &candidates), None);
+    }
+
+    #[test]
+    fn measures_edit_distance() {
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("same", "same"), 0);
+    }
+}
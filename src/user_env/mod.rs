@@ -1,3 +1,4 @@
 mod user_env;
 
+#[allow(unused_imports)]
 pub use user_env::*;
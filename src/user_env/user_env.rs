@@ -1,3 +1,207 @@
-// automatically export ts classes
-// default integer types for C, C++, Rust
+use crate::common::handle_result_error;
+use crate::common::MError;
+use crate::models::{Case, Language, Severity};
+use crate::parser::{parse_language, LINT_RULES};
+
 // set default array length
+
+// crates the generated Rust may depend on for the built-in date, uuid and decimal types
+const RUST_CRATES: [&str; 3] = ["chrono", "uuid", "rust_decimal"];
+
+// settings given on the command line, e.g. `class_mapper --case rs=preserve,java=snake user.txt`
+#[derive(Clone, Debug)]
+pub struct UserEnv {
+    pub files: Vec<String>,
+    pub cases: Vec<(Language, Case)>,
+    pub rust_crates: Vec<String>,
+    // emit aliases as `pub struct UserId(pub String)` rather than `pub type UserId = String`
+    pub rust_newtypes: bool,
+    // `--lint field-case=error,explicit-access=warn`
+    pub lint_levels: Vec<(String, Severity)>,
+}
+
+impl UserEnv {
+    pub fn from_args(args: &[String]) -> UserEnv {
+        let mut user_env = UserEnv {
+            files: Vec::new(),
+            cases: Vec::new(),
+            rust_crates: Vec::new(),
+            rust_newtypes: true,
+            lint_levels: Vec::new(),
+        };
+        let mut index = 0;
+        while index < args.len() {
+            let arg = args.get(index).unwrap();
+            if arg.as_str() == "--case" {
+                index += 1;
+                match args.get(index) {
+                    Some(value) => user_env.add_cases(value),
+                    None => {
+                        let message = "Expected `language=case` pairs after `--case`.".to_string();
+                        handle_result_error(MError::UserEnvError(message));
+                    }
+                }
+            } else if arg.as_str() == "--rs-crates" {
+                index += 1;
+                match args.get(index) {
+                    Some(value) => user_env.add_rust_crates(value),
+                    None => {
+                        let message = "Expected a list of crates after `--rs-crates`.".to_string();
+                        handle_result_error(MError::UserEnvError(message));
+                    }
+                }
+            } else if arg.as_str() == "--rs-aliases" {
+                index += 1;
+                match args.get(index).map(|a| a.as_str()) {
+                    Some("newtype") => user_env.rust_newtypes = true,
+                    Some("alias") => user_env.rust_newtypes = false,
+                    _ => {
+                        let message = "Expected either `newtype` or `alias` after `--rs-aliases`."
+                            .to_string();
+                        handle_result_error(MError::UserEnvError(message));
+                    }
+                }
+            } else if arg.as_str() == "--lint" {
+                index += 1;
+                match args.get(index) {
+                    Some(value) => user_env.add_lint_levels(value),
+                    None => {
+                        let message = "Expected `rule=level` pairs after `--lint`.".to_string();
+                        handle_result_error(MError::UserEnvError(message));
+                    }
+                }
+            } else if arg.starts_with("--") {
+                let mut message = "Unknown option ".to_string();
+                message.push_str(arg.as_str());
+                handle_result_error(MError::UserEnvError(message));
+            } else {
+                user_env.files.push(arg.clone());
+            }
+            index += 1;
+        }
+        user_env
+    }
+
+    fn add_cases(&mut self, value: &str) {
+        for pair in value.split(',').filter(|p| !p.is_empty()) {
+            let (language, case_name) = match pair.split_once('=') {
+                Some(p) => p,
+                None => {
+                    let mut message = "Expected `language=case` but found ".to_string();
+                    message.push_str(pair);
+                    handle_result_error(MError::UserEnvError(message));
+                    panic!()
+                }
+            };
+            let language = match parse_language(language) {
+                Some(l) => l,
+                None => {
+                    let mut message = "Unknown language in `--case`. \
+                     Expected either `rs`, `ts`, `c`, or `java` but found "
+                        .to_string();
+                    message.push_str(language);
+                    handle_result_error(MError::UserEnvError(message));
+                    panic!()
+                }
+            };
+            let case = match case_name {
+                "snake" => Case::SNAKE,
+                "camel" => Case::CAMEL,
+                "pascal" => Case::PASCAL,
+                "preserve" => Case::PRESERVE,
+                _ => {
+                    let mut message = "Unknown case in `--case`. \
+                     Expected either `snake`, `camel`, `pascal`, or `preserve` but found "
+                        .to_string();
+                    message.push_str(case_name);
+                    handle_result_error(MError::UserEnvError(message));
+                    panic!()
+                }
+            };
+            self.cases.retain(|(l, _c)| *l != language);
+            self.cases.push((language, case));
+        }
+    }
+
+    // `--rs-crates chrono,uuid` maps `datetime` and `uuid` to the types of those crates
+    fn add_rust_crates(&mut self, value: &str) {
+        for name in value.split(',').filter(|n| !n.is_empty()) {
+            if !RUST_CRATES.contains(&name) {
+                let mut message = "Unsupported crate in `--rs-crates`. \
+                 Expected either `chrono`, `uuid`, or `rust_decimal` but found "
+                    .to_string();
+                message.push_str(name);
+                handle_result_error(MError::UserEnvError(message));
+            }
+            if !self.rust_crates.iter().any(|c| c == name) {
+                self.rust_crates.push(name.to_string());
+            }
+        }
+    }
+
+    fn add_lint_levels(&mut self, value: &str) {
+        for pair in value.split(',').filter(|p| !p.is_empty()) {
+            let (rule, level) = match pair.split_once('=') {
+                Some(p) => p,
+                None => {
+                    let mut message = "Expected `rule=level` but found ".to_string();
+                    message.push_str(pair);
+                    handle_result_error(MError::UserEnvError(message));
+                    panic!()
+                }
+            };
+            if !LINT_RULES.iter().any(|(r, _s)| *r == rule) {
+                let rules: Vec<&str> = LINT_RULES.iter().map(|(r, _s)| *r).collect();
+                let message = format!(
+                    "Unknown lint rule in `--lint`. Expected one of {} but found {}",
+                    rules.join(", "),
+                    rule
+                );
+                handle_result_error(MError::UserEnvError(message));
+            }
+            let severity = match level {
+                "allow" => Severity::ALLOW,
+                "warn" => Severity::WARN,
+                "error" => Severity::ERROR,
+                _ => {
+                    let mut message = "Unknown level in `--lint`. \
+                     Expected either `allow`, `warn`, or `error` but found "
+                        .to_string();
+                    message.push_str(level);
+                    handle_result_error(MError::UserEnvError(message));
+                    panic!()
+                }
+            };
+            self.lint_levels.retain(|(r, _s)| r != rule);
+            self.lint_levels.push((rule.to_string(), severity));
+        }
+    }
+
+    pub fn get_lint_levels(&self) -> Vec<(String, Severity)> {
+        self.lint_levels.clone()
+    }
+
+    pub fn uses_rust_newtypes(&self) -> bool {
+        self.rust_newtypes
+    }
+
+    pub fn get_rust_crates(&self) -> Vec<String> {
+        self.rust_crates.clone()
+    }
+
+    pub fn get_files(&self) -> Vec<String> {
+        self.files.clone()
+    }
+
+    // field name convention of `language`, snake_case for Rust and C and camelCase for Java
+    // and TypeScript
+    pub fn get_case(&self, language: &Language) -> Case {
+        if let Some((_l, case)) = self.cases.iter().find(|(l, _c)| l == language) {
+            return case.clone();
+        }
+        match language {
+            Language::RUST | Language::C => Case::SNAKE,
+            Language::JAVA | Language::TYPESCRIPT => Case::CAMEL,
+        }
+    }
+}
@@ -0,0 +1,281 @@
+mod common;
+
+use common::{map, squash};
+
+#[test]
+fn recursive_fields_are_pointers() {
+    let run = map(
+        "c_recursive",
+        "targets [ c ]\n\
+         pub Person { pub contact: Person, pub friends: List<Person>, pub home: Address }\n\
+         pub Address { pub owner: Person, pub street: String }\n\
+         pub Node { pub pair: (Node, int), pub value: int }",
+        &[],
+    );
+    let person = squash(&run.read("Person.c"));
+    assert!(person.contains("struct Person* contact;"));
+    assert!(person.contains("Person* friends;"));
+    assert!(person.contains("struct Address* home;"));
+    assert!(squash(&run.read("Address.c")).contains("struct Person* owner;"));
+    // only the members on the cycle are behind a pointer, the tuple itself stays inline
+    assert!(squash(&run.read("Node.c")).contains("struct { struct Node* item0; int item1; } pair;"));
+}
+
+#[test]
+fn fields_off_a_cycle_stay_inline() {
+    let run = map(
+        "c_inline",
+        "targets [ c ]\n\
+         pub User { pub home: Address, pub pair: (Address, int) }\n\
+         pub Address { pub street: String }",
+        &[],
+    );
+    let user = squash(&run.read("User.c"));
+    assert!(user.contains("struct Address home;"));
+    assert!(user.contains("struct { struct Address item0; int item1; } pair;"));
+}
+
+#[test]
+fn interfaces_are_left_out() {
+    let run = map(
+        "c_interfaces",
+        "targets [ c ]\n\
+         pub interface Named { name: String }\n\
+         pub User implements Named { pub name: String }",
+        &[],
+    );
+    assert!(!run.exists("Named.c"));
+    assert!(run
+        .stdout
+        .contains("Skipping C output for interface \"Named\""));
+    assert!(squash(&run.read("User.c")).contains("char* name;"));
+}
+
+#[test]
+fn generics_are_written_once_per_instantiation() {
+    let run = map(
+        "c_generics",
+        "targets [ c ]\n\
+         pub Page<T> { pub items: List<T>, pub first: T, pub total: int }\n\
+         pub Unused<T> { pub value: T }\n\
+         pub User { pub page: Page<Address>, pub ids: Page<int> }\n\
+         pub Address { pub street: String }",
+        &[],
+    );
+    let page = squash(&run.read("Page.c"));
+    assert!(page.contains(
+        "typedef struct Page_Address { Address* items; struct Address first; int total; }"
+    ));
+    assert!(page.contains("typedef struct Page_int { int* items; int first; int total; }"));
+    assert!(squash(&run.read("User.c")).contains("struct Page_Address page; struct Page_int ids;"));
+    assert!(!run.exists("Unused.c"));
+}
+
+#[test]
+fn doc_comments_become_block_comments() {
+    let run = map(
+        "c_comments",
+        "targets [ c ]\n/// A user\npub User {\n    /// login name\n    pub name: String\n}",
+        &[],
+    );
+    let user = run.read("User.c");
+    assert!(user.starts_with("/*\n * A user\n */\ntypedef struct User {"));
+    assert!(user.contains("\t/*\n\t * login name\n\t */\n\tchar* name;"));
+}
+
+#[test]
+fn defaults_are_set_by_an_init_function() {
+    let run = map(
+        "c_defaults",
+        "targets [ c ]\npub User { pub age: int = 5, pub name: String = \"a b\", pub ids: List<int> = [1, 2], pub plain: int }",
+        &[],
+    );
+    let user = squash(&run.read("User.c"));
+    assert!(user.contains(
+        "struct User init_User(void) { struct User value = {0}; value.age = 5; \
+         value.name = \"a b\"; static int ids_default[] = {1, 2}; value.ids = ids_default; \
+         return value; }"
+    ));
+    let run = map(
+        "c_no_defaults",
+        "targets [ c ]\npub User { pub age: int }",
+        &[],
+    );
+    assert!(!run.read("User.c").contains("init_User"));
+}
+
+#[test]
+fn constraints_are_checked_by_a_validate_function() {
+    let run = map(
+        "c_constraints",
+        "targets [ c ]\npub User { @min(0) pub age: int, @length(1, 64) @pattern(\"^[a-z]+$\") pub name: String }",
+        &[],
+    );
+    let user = squash(&run.read("User.c"));
+    assert!(user.contains("#include <regex.h>"));
+    assert!(user.contains("bool User_validate(const struct User* value) {"));
+    assert!(user.contains("if (value->age < 0) return false; /* age must be at least 0 */"));
+    assert!(user.contains("if (value->name == NULL || strlen(value->name) < 1) return false;"));
+    assert!(user.contains("if (!matches_pattern(value->name, \"^[a-z]+$\")) return false;"));
+}
+
+#[test]
+fn overridden_names_are_used_by_references() {
+    let run = map(
+        "c_renames",
+        "targets [ c ]\n@name(c = \"person_t\")\npub User { pub id: String }\npub Holder { pub user: User }",
+        &[],
+    );
+    assert!(squash(&run.read("person_t.c")).contains("typedef struct person_t { char* id; }"));
+    assert!(squash(&run.read("Holder.c")).contains("struct person_t user;"));
+}
+
+#[test]
+fn fields_are_snake_case() {
+    let run = map(
+        "c_case",
+        "targets [ c ]\npub User { pub isUsed: bool, pub HTTPServer: String }",
+        &[],
+    );
+    assert!(squash(&run.read("User.c")).contains("bool is_used; char* http_server;"));
+    let run = map(
+        "c_case_preserved",
+        "targets [ c ]\npub User { pub isUsed: bool }",
+        &["--case", "c=preserve"],
+    );
+    assert!(run.read("User.c").contains("bool isUsed;"));
+}
+
+#[test]
+fn keywords_are_escaped() {
+    let run = map(
+        "c_keywords",
+        "targets [ c ]\npub User { pub static: int, pub class: int }",
+        &[],
+    );
+    assert!(squash(&run.read("User.c")).contains("int static_; int class;"));
+}
+
+#[test]
+fn sized_integers_use_stdint() {
+    let run = map(
+        "c_widths",
+        "targets [ c ]\npub Widths { pub a: i8, pub b: u16, pub c: i64, pub d: u64, pub e: float }",
+        &[],
+    );
+    let widths = squash(&run.read("Widths.c"));
+    assert!(widths.starts_with("#include <stdint.h>"));
+    assert!(widths.contains("int8_t a; uint16_t b; int64_t c; uint64_t d; float e;"));
+}
+
+#[test]
+fn fixed_arrays_are_inline() {
+    let run = map(
+        "c_fixed_arrays",
+        "targets [ c ]\npub Grid { pub cells: [int; 4], pub names: [String; 2] }",
+        &[],
+    );
+    assert!(squash(&run.read("Grid.c")).contains("int cells[4]; char* names[2];"));
+}
+
+#[test]
+fn built_in_types_document_their_encoding() {
+    let run = map(
+        "c_built_ins",
+        "targets [ c ]\npub Times { pub at: datetime, pub id: uuid, pub data: bytes }",
+        &[],
+    );
+    let times = squash(&run.read("Times.c"));
+    assert!(times.contains("int64_t at; /* milliseconds since the Unix epoch, UTC */"));
+    assert!(times.contains("char* id; /* 36 character hyphenated UUID */"));
+    assert!(times.contains("uint8_t* data; /* byte buffer */"));
+}
+
+#[test]
+fn unions_are_tagged_structs() {
+    let run = map(
+        "c_unions",
+        "targets [ c ]\n\
+         pub Circle { pub r: double }\n\
+         pub Square { pub side: double }\n\
+         pub union Shape(kind) { circle: Circle, square: Square }",
+        &[],
+    );
+    let shape = squash(&run.read("Shape.c"));
+    assert!(shape.contains("enum Shape_Kind { Shape_CIRCLE, Shape_SQUARE, };"));
+    assert!(shape.contains(
+        "enum Shape_Kind kind; union { struct Circle circle; struct Square square; } value;"
+    ));
+}
+
+#[test]
+fn aliases_are_typedefs() {
+    let run = map(
+        "c_aliases",
+        "targets [ c ]\n\
+         pub type UserId = String\n\
+         pub type Home = Address\n\
+         pub Address { pub street: String }\n\
+         pub User { pub id: UserId, pub home: Home }",
+        &[],
+    );
+    assert!(squash(&run.read("UserId.c")).contains("typedef char* UserId;"));
+    assert!(squash(&run.read("Home.c")).contains("typedef struct Address Home;"));
+    assert!(squash(&run.read("User.c")).contains("UserId id; Home home;"));
+}
+
+#[test]
+fn constants_are_defines_and_inlined() {
+    let run = map(
+        "c_constants",
+        "targets [ c ]\n\
+         pub const MAX_AGE: int = 150\n\
+         pub const SIZE: int = 4\n\
+         pub const GREETING: String = \"hi\"\n\
+         pub User { pub age: int = MAX_AGE, pub grid: [int; SIZE] }",
+        &[],
+    );
+    assert!(squash(&run.read("MAX_AGE.c")).contains("#define MAX_AGE 150"));
+    assert!(squash(&run.read("GREETING.c")).contains("#define GREETING \"hi\""));
+    let user = squash(&run.read("User.c"));
+    assert!(user.contains("int grid[4];"));
+    assert!(user.contains("value.age = 150;"));
+}
+
+#[test]
+fn tuples_are_anonymous_structs() {
+    let run = map(
+        "c_tuples",
+        "targets [ c ]\npub User { pub pair: (int, String) }",
+        &[],
+    );
+    assert!(squash(&run.read("User.c")).contains("struct { int item0; char* item1; } pair;"));
+}
+
+#[test]
+fn attributes_are_emitted_verbatim() {
+    let run = map(
+        "c_attributes",
+        "targets [ c ]\n\
+         @c(\"__attribute__((packed))\")\n\
+         pub User { @c(\"__attribute__((aligned(8)))\") pub secret: String }",
+        &[],
+    );
+    let user = squash(&run.read("User.c"));
+    assert!(user.contains("typedef struct __attribute__((packed)) User {"));
+    assert!(user.contains("char* secret __attribute__((aligned(8)));"));
+}
+
+#[test]
+fn deprecations_are_attributes() {
+    let run = map(
+        "c_deprecations",
+        "targets [ c ]\n\
+         @deprecated(\"use User\")\n\
+         pub Person { @deprecated(\"use fullName\") pub name: String }",
+        &[],
+    );
+    let person = squash(&run.read("Person.c"));
+    assert!(person.contains("typedef struct __attribute__((deprecated(\"use User\"))) Person {"));
+    assert!(person.contains("char* name __attribute__((deprecated(\"use fullName\")));"));
+}
@@ -0,0 +1,69 @@
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+// a run of the class_mapper binary in a directory of its own
+pub struct Run {
+    pub directory: PathBuf,
+    pub stdout: String,
+    pub success: bool,
+}
+
+impl Run {
+    // content of a file generated by the run
+    pub fn read(&self, file: &str) -> String {
+        match fs::read_to_string(self.directory.join(file)) {
+            Ok(content) => content,
+            Err(e) => panic!("{} was not generated: {}\n{}", file, e, self.stdout),
+        }
+    }
+
+    pub fn exists(&self, file: &str) -> bool {
+        self.directory.join(file).exists()
+    }
+}
+
+// writes the schema `files` to a fresh directory named after the test and runs class_mapper
+// there with `args`
+pub fn run(name: &str, files: &[(&str, &str)], args: &[&str]) -> Run {
+    let directory = std::env::temp_dir().join(format!("class_mapper_test_{}", name));
+    let _ = fs::remove_dir_all(&directory);
+    fs::create_dir_all(&directory).unwrap();
+    for (file, content) in files.iter() {
+        fs::write(directory.join(file), content).unwrap();
+    }
+    let output = Command::new(env!("CARGO_BIN_EXE_class_mapper"))
+        .args(args)
+        .current_dir(&directory)
+        .output()
+        .unwrap();
+    Run {
+        directory,
+        stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+        success: output.status.success(),
+    }
+}
+
+// runs a single schema file that is expected to map successfully
+pub fn map(name: &str, schema: &str, args: &[&str]) -> Run {
+    let mut args = args.to_vec();
+    args.push("schema.txt");
+    let run = run(name, &[("schema.txt", schema)], &args);
+    assert!(run.success, "mapping failed:\n{}", run.stdout);
+    run
+}
+
+// runs a single schema file that is expected to fail, returning the error output
+pub fn fail(name: &str, schema: &str) -> String {
+    let run = run(name, &[("schema.txt", schema)], &["schema.txt"]);
+    assert!(!run.success, "mapping succeeded:\n{}", run.stdout);
+    run.stdout
+}
+
+// the generated text with runs of whitespace collapsed to single spaces, so that assertions do
+// not depend on the exact indentation
+pub fn squash(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
@@ -0,0 +1,250 @@
+mod common;
+
+use common::{fail, map, run};
+
+#[test]
+fn reports_a_class_left_open() {
+    let stdout = fail("error_open_class", "[ rs ]\npub A {\n    a: int\n");
+    assert!(stdout.contains("Reached the end of the file before `}` closed class `A`."));
+}
+
+#[test]
+fn reports_a_class_without_languages() {
+    let stdout = fail(
+        "error_no_languages",
+        "targets [ rs ]\n[ -rs ]\nA { a: int }",
+    );
+    assert!(stdout.contains("`A` is not generated for any language."));
+}
+
+#[test]
+fn reports_adjustments_without_targets() {
+    let stdout = fail("error_no_targets", "[-c]\nA { a: int }");
+    assert!(stdout.contains("`+` and `-` in a `[ ... ]` header adjust the file-level"));
+}
+
+#[test]
+fn reports_a_pattern_that_does_not_compile() {
+    let stdout = fail(
+        "error_pattern",
+        "[ ts ]\nUser { @pattern(\"^(a\") pub name: String }",
+    );
+    assert!(stdout
+        .contains("Invalid `@pattern` on field `name`: the regular expression does not compile."));
+}
+
+#[test]
+fn reports_patterns_c_reads_differently() {
+    let stdout = fail(
+        "error_posix_pattern",
+        "[ ts, c ]\nUser { @pattern(\"^\\d+$\") pub zip: String }",
+    );
+    assert!(stdout.contains("C uses POSIX regular expressions"));
+    let run = map(
+        "posix_pattern_without_c",
+        "[ ts ]\nUser { @pattern(\"^\\d+$\") pub zip: String }",
+        &[],
+    );
+    assert!(run.read("User.ts").contains("\\\\d+$"));
+}
+
+#[test]
+fn reports_an_interface_field_that_is_not_implemented() {
+    let stdout = fail(
+        "error_interface_missing",
+        "targets [ rs ]\npub interface Named { name: String }\npub User implements Named { age: int }",
+    );
+    assert!(stdout.contains(
+        "Class `User` implements `Named` but does not declare the required field `name`."
+    ));
+    let stdout = fail(
+        "error_interface_type",
+        "targets [ rs ]\npub interface Named { name: String }\npub User implements Named { name: int }",
+    );
+    assert!(stdout.contains(
+        "Field `name` of class `User` has type INTEGER but interface `Named` requires STRING."
+    ));
+}
+
+#[test]
+fn reports_the_wrong_number_of_type_arguments() {
+    let stdout = fail(
+        "error_generic_arguments",
+        "targets [ rs ]\npub Page<T> { items: List<T> }\npub User { page: Page<int, String> }",
+    );
+    assert!(stdout.contains(
+        "Field `page` of class `User` uses `Page` with 2 type argument(s) but `Page<T>` expects 1."
+    ));
+}
+
+#[test]
+fn reports_a_default_of_the_wrong_type() {
+    let stdout = fail("error_default", "[ rs ]\nUser { age: int = \"x\" }");
+    assert!(
+        stdout.contains("Default value `\"x\"` does not match the type INTEGER of field `age`.")
+    );
+}
+
+#[test]
+fn reports_constraints_on_the_wrong_type() {
+    let stdout = fail("error_constraint", "[ rs ]\nUser { @min(0) name: String }");
+    assert!(stdout.contains(
+        "Invalid `@min` on field `name`: only numeric fields can have a minimum or maximum."
+    ));
+    let stdout = fail(
+        "error_constraint_alias",
+        "targets [ rs ]\ntype Age = int\nUser { @length(1) age: Age }",
+    );
+    assert!(stdout.contains("Invalid `@length` on field `age` of class `User`"));
+}
+
+#[test]
+fn reports_import_cycles_and_missing_imports() {
+    let run = run(
+        "error_import_cycle",
+        &[
+            ("a.txt", "import \"b.txt\"\n[ rs ]\nA { b: B }"),
+            ("b.txt", "import \"a.txt\"\n[ rs ]\nB { a: int }"),
+        ],
+        &["a.txt"],
+    );
+    assert!(!run.success);
+    assert!(run.stdout.contains("Import cycle detected: "));
+    assert!(run.stdout.contains("a.txt -> "));
+    let stdout = fail(
+        "error_import_missing",
+        "import \"missing.txt\"\n[ rs ]\nC { a: int }",
+    );
+    assert!(stdout.contains("missing.txt (imported from "));
+}
+
+#[test]
+fn reports_unknown_types_with_a_suggestion() {
+    let stdout = fail(
+        "error_unknown_type",
+        "targets [ rs ]\nUser { home: Adress }\nAddress { a: int }",
+    );
+    assert!(stdout.contains("Unknown type `Adress` in field `home` of class `User` ("));
+    assert!(stdout.contains("schema.txt). Did you mean `Address`?"));
+    let stdout = fail(
+        "error_unknown_type_far",
+        "targets [ rs ]\nUser { x: Invoice }",
+    );
+    assert!(stdout.contains("Unknown type `Invoice`"));
+    assert!(!stdout.contains("Did you mean"));
+}
+
+#[test]
+fn reports_types_declared_in_a_file_that_is_not_imported() {
+    let run = run(
+        "error_not_imported",
+        &[
+            ("a.txt", "import \"b.txt\"\n[ rs ]\nA { b: B }"),
+            ("b.txt", "[ rs ]\nB { c: C }"),
+            ("c.txt", "import \"a.txt\"\n[ rs ]\nC { a: int }"),
+        ],
+        &["a.txt", "c.txt"],
+    );
+    assert!(!run.success);
+    assert!(run
+        .stdout
+        .contains("Unknown type `C` in field `c` of class `B`"));
+    assert!(run.stdout.contains("c.txt which is not imported."));
+}
+
+#[test]
+fn reports_defaults_outside_the_integer_width() {
+    let stdout = fail("error_width", "[ rs ]\nUser { age: u8 = 256 }");
+    assert!(stdout.contains("Default value `256` does not match the type U8 of field `age`."));
+}
+
+#[test]
+fn reports_invalid_union_variants() {
+    let stdout = fail(
+        "error_union_payload",
+        "targets [ rs ]\npub interface Named { name: String }\npub union Shape(kind) { named: Named }",
+    );
+    assert!(stdout.contains(
+        "Variant `named` of union `Shape` must hold a class that is not an interface, union or generic."
+    ));
+    let stdout = fail(
+        "error_union_discriminator",
+        "targets [ rs ]\npub Circle { pub kind: String }\npub union Shape(kind) { circle: Circle }",
+    );
+    assert!(stdout.contains(
+        "Variant `circle` of union `Shape` holds `Circle` which already has a field named after the discriminator `kind`."
+    ));
+}
+
+#[test]
+fn reports_aliases_of_composite_types() {
+    let stdout = fail("error_alias", "targets [ rs ]\npub type Scores = List<int>");
+    assert!(stdout.contains(
+        "Alias `Scores` must stand for a single type such as `String` or a class, not `List<int>`."
+    ));
+}
+
+#[test]
+fn reports_misused_constants() {
+    let stdout = fail(
+        "error_constant_type",
+        "targets [ rs ]\npub const X: int = \"a\"",
+    );
+    assert!(stdout.contains("Value `\"a\"` does not match the type INTEGER of constant `X`."));
+    let stdout = fail(
+        "error_constant_unknown",
+        "targets [ rs ]\npub User { @max(NOPE) pub age: int }",
+    );
+    assert!(stdout.contains("Unknown constant `NOPE` in field `age` of class `User`"));
+}
+
+#[test]
+fn reports_attributes_for_languages_not_generated() {
+    let stdout = fail(
+        "error_attribute",
+        "[ rs ]\nUser { @java(\"@JsonIgnore\") pub secret: String }",
+    );
+    assert!(stdout.contains(
+        "Invalid `@java` on field `secret`: the language is not in the header so the attribute would never be emitted."
+    ));
+}
+
+#[test]
+fn reports_classes_declared_twice() {
+    let stdout = fail(
+        "error_duplicate_class",
+        "targets [ rs ]\npub User { pub a: int }\n\npub User { pub b: int }",
+    );
+    // the files are named by their full path
+    assert!(stdout.contains("`User` is declared more than once, in "));
+    assert!(stdout.contains("schema.txt:2 and in "));
+    assert!(stdout.contains("schema.txt:4."));
+    // across files the message names both
+    let run = run(
+        "error_duplicate_class_files",
+        &[
+            (
+                "a.txt",
+                "import \"b.txt\"\ntargets [ rs ]\npub User { pub a: int }",
+            ),
+            ("b.txt", "targets [ rs ]\npub User { pub b: int }"),
+        ],
+        &["a.txt"],
+    );
+    assert!(!run.success);
+    assert!(run
+        .stdout
+        .contains("`User` is declared more than once, in "));
+    assert!(run.stdout.contains("a.txt:3") && run.stdout.contains("b.txt:2"));
+}
+
+#[test]
+fn reports_fields_declared_twice() {
+    let stdout = fail(
+        "error_duplicate_field",
+        "targets [ rs ]\npub User {\n    pub age: int,\n    pub name: String,\n    pub age: int\n}",
+    );
+    assert!(stdout.contains("Field `age` is declared more than once in class `User`, in "));
+    assert!(stdout.contains("schema.txt:3 and in "));
+    assert!(stdout.contains("schema.txt:5."));
+}
@@ -0,0 +1,27 @@
+mod common;
+
+use common::run;
+
+#[test]
+fn check_lists_unformatted_files_without_writing_them() {
+    let messy = "targets [ rs ]\npub User {  pub age: int,   pub name: String }";
+    let checked = run(
+        "fmt_check",
+        &[("messy.txt", messy)],
+        &["fmt", "--check", "messy.txt"],
+    );
+    assert!(!checked.success);
+    assert!(checked.stdout.contains("Would reformat \"messy.txt\""));
+    assert_eq!(checked.read("messy.txt"), messy);
+
+    let formatted = run("fmt_write", &[("messy.txt", messy)], &["fmt", "messy.txt"]);
+    assert!(formatted.success, "fmt failed:\n{}", formatted.stdout);
+    let content = formatted.read("messy.txt");
+    assert_ne!(content, messy);
+    let checked = run(
+        "fmt_check_clean",
+        &[("clean.txt", content.as_str())],
+        &["fmt", "--check", "clean.txt"],
+    );
+    assert!(checked.success, "fmt --check failed:\n{}", checked.stdout);
+}
@@ -0,0 +1,262 @@
+mod common;
+
+use common::{map, run, squash};
+
+#[test]
+fn classes_of_other_packages_are_imported() {
+    let run = run(
+        "java_packages",
+        &[
+            (
+                "users.txt",
+                "package com.acme.users;\n\
+                 import \"places.txt\"\n\
+                 targets [ java ]\n\
+                 pub User { pub home: Address, pub manager: Manager }\n\
+                 pub Manager { pub name: String }",
+            ),
+            (
+                "places.txt",
+                "package com.acme.places;\n[ java ]\npub Address { pub street: String }",
+            ),
+        ],
+        &["users.txt"],
+    );
+    assert!(run.success, "mapping failed:\n{}", run.stdout);
+    let user = squash(&run.read("com/acme/users/User.java"));
+    assert!(user.starts_with("package com.acme.users; import com.acme.places.Address; public"));
+    assert!(!user.contains("import com.acme.users.Manager"));
+}
+
+#[test]
+fn interfaces_are_implemented_through_getters() {
+    let run = map(
+        "java_interfaces",
+        "targets [ java ]\n\
+         pub interface Named { name: String, tags: List<String> }\n\
+         pub User implements Named { pub name: String, pub tags: List<String>, pub age: int }",
+        &[],
+    );
+    let named = squash(&run.read("Named.java"));
+    assert!(named.contains("public interface Named { String getName(); List<String> getTags(); }"));
+    let user = squash(&run.read("User.java"));
+    assert!(user.contains("public class User implements Named {"));
+    assert!(user.contains("public String getName() { return this.name; }"));
+    assert!(user.contains("public List<String> getTags() { return this.tags; }"));
+}
+
+#[test]
+fn generics_keep_their_type_parameters() {
+    let run = map(
+        "java_generics",
+        "targets [ java ]\npub Page<T> { pub items: List<T>, pub first: T }\npub User { pub page: Page<String> }",
+        &[],
+    );
+    assert!(squash(&run.read("Page.java"))
+        .contains("public class Page<T> { public List<T> items; public T first; }"));
+    assert!(squash(&run.read("User.java")).contains("public Page<String> page;"));
+}
+
+#[test]
+fn doc_comments_become_javadoc() {
+    let run = map(
+        "java_comments",
+        "targets [ java ]\n/// A user\npub User {\n    /// login name\n    pub name: String\n}",
+        &[],
+    );
+    let user = run.read("User.java");
+    assert!(user.starts_with("/**\n * A user\n */\npublic class User {"));
+    assert!(user.contains("\t/**\n\t * login name\n\t */\n\tpublic String name;"));
+}
+
+#[test]
+fn defaults_are_field_initializers() {
+    let run = map(
+        "java_defaults",
+        "targets [ java ]\n\
+         pub User { pub age: int = 5, pub name: String = \"a b\", pub tags: List<String> = [\"x\", \"y\"], pub plain: int }",
+        &[],
+    );
+    let user = squash(&run.read("User.java"));
+    assert!(user.contains("import java.util.ArrayList;"));
+    assert!(user.contains(
+        "public int age = 5; public String name = \"a b\"; \
+         public List<String> tags = new ArrayList<>(List.of(\"x\", \"y\")); public int plain;"
+    ));
+}
+
+#[test]
+fn constraints_are_bean_validation_annotations() {
+    let run = map(
+        "java_constraints",
+        "targets [ java ]\n\
+         pub User { @min(0) @max(150) pub age: int, @length(1, 64) @pattern(\"^[a-z]+$\") pub name: String, @nonempty pub tags: List<String> }",
+        &[],
+    );
+    let user = squash(&run.read("User.java"));
+    assert!(user.contains("import jakarta.validation.constraints.*;"));
+    assert!(user.contains("@Min(0) @Max(150) public int age;"));
+    assert!(user
+        .contains("@Size(min = 1, max = 64) @Pattern(regexp = \"^[a-z]+$\") public String name;"));
+    assert!(user.contains("@NotEmpty public List<String> tags;"));
+}
+
+#[test]
+fn fields_are_camel_case_and_keep_their_wire_names() {
+    let run = map(
+        "java_case",
+        "targets [ java ]\npub User { pub is_used: bool, pub age: int }",
+        &[],
+    );
+    let user = squash(&run.read("User.java"));
+    assert!(user.contains("@JsonProperty(\"is_used\") public boolean isUsed;"));
+    assert!(user.contains("public int age;"));
+}
+
+#[test]
+fn keywords_are_escaped_and_keep_their_wire_names() {
+    let run = map(
+        "java_keywords",
+        "targets [ java ]\npub User { pub class: int, pub type: String }",
+        &[],
+    );
+    let user = squash(&run.read("User.java"));
+    assert!(user.contains("@JsonProperty(\"class\") public int _class;"));
+    assert!(user.contains("public String type;"));
+}
+
+#[test]
+fn fixed_arrays_are_allocated_with_their_length() {
+    let run = map(
+        "java_fixed_arrays",
+        "targets [ java ]\npub Grid { pub cells: [int; 4] }",
+        &[],
+    );
+    assert!(squash(&run.read("Grid.java")).contains(
+        "public static final int CELLS_LENGTH = 4; public int[] cells = new int[CELLS_LENGTH];"
+    ));
+}
+
+#[test]
+fn built_in_types_use_the_standard_library() {
+    let run = map(
+        "java_built_ins",
+        "targets [ java ]\n\
+         pub Times { pub at: datetime, pub on: date, pub took: duration, pub id: uuid, pub price: decimal, pub data: bytes }",
+        &[],
+    );
+    let times = squash(&run.read("Times.java"));
+    for import in [
+        "java.time.Instant",
+        "java.time.LocalDate",
+        "java.time.Duration",
+        "java.util.UUID",
+        "java.math.BigDecimal",
+    ] {
+        assert!(
+            times.contains(format!("import {};", import).as_str()),
+            "{}",
+            import
+        );
+    }
+    assert!(times.contains(
+        "public Instant at; public LocalDate on; public Duration took; public UUID id; \
+         public BigDecimal price; public byte[] data;"
+    ));
+}
+
+#[test]
+fn unions_are_sealed_interfaces() {
+    let run = map(
+        "java_unions",
+        "targets [ java ]\n\
+         pub Circle { pub r: double }\n\
+         pub Square { pub side: double }\n\
+         pub union Shape(kind) { circle: Circle, square: Square }",
+        &[],
+    );
+    let shape = squash(&run.read("Shape.java"));
+    assert!(shape.contains("@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, property = \"kind\")"));
+    assert!(shape.contains("@JsonSubTypes.Type(value = Circle.class, name = \"circle\"),"));
+    assert!(shape.contains("public sealed interface Shape permits Circle, Square {"));
+    assert!(
+        squash(&run.read("Circle.java")).contains("public final class Circle implements Shape {")
+    );
+}
+
+#[test]
+fn aliases_are_inlined() {
+    let run = map(
+        "java_aliases",
+        "targets [ java ]\npub type UserId = String\npub User { @length(1, 8) pub id: UserId }",
+        &[],
+    );
+    assert!(!run.exists("UserId.java"));
+    assert!(run.stdout.contains("Inlining Java alias \"UserId\""));
+    assert!(squash(&run.read("User.java")).contains("@Size(min = 1, max = 8) public String id;"));
+}
+
+#[test]
+fn constants_are_grouped_and_inlined() {
+    let run = map(
+        "java_constants",
+        "targets [ java ]\n\
+         pub const MAX_AGE: int = 150\n\
+         pub const GREETING: String = \"hi\"\n\
+         pub User { @max(MAX_AGE) pub age: int = MAX_AGE }",
+        &[],
+    );
+    let constants = squash(&run.read("Constants.java"));
+    assert!(constants.contains("public final class Constants {"));
+    assert!(constants.contains("public static final int MAX_AGE = 150;"));
+    assert!(constants.contains("public static final String GREETING = \"hi\";"));
+    assert!(constants.contains("private Constants() {}"));
+    assert!(!run.exists("MAX_AGE.java"));
+    assert!(squash(&run.read("User.java")).contains("@Max(150) public int age = 150;"));
+}
+
+#[test]
+fn tuples_are_array_shaped_records() {
+    let run = map(
+        "java_tuples",
+        "targets [ java ]\npub User { pub pair: (int, String) }",
+        &[],
+    );
+    let user = squash(&run.read("User.java"));
+    assert!(user.contains("import com.fasterxml.jackson.annotation.JsonFormat;"));
+    assert!(user.contains(
+        "@JsonFormat(shape = JsonFormat.Shape.ARRAY) public record Pair<T0, T1>(T0 item0, T1 item1) {}"
+    ));
+    assert!(user.contains("public Pair<Integer, String> pair;"));
+}
+
+#[test]
+fn attributes_are_emitted_verbatim() {
+    let run = map(
+        "java_attributes",
+        "targets [ java, rs ]\n\
+         pub User { @rs(\"#[serde(skip)]\") @java(\"@JsonIgnore\") pub secret: String }",
+        &[],
+    );
+    let user = run.read("User.java");
+    assert!(user.contains("\t@JsonIgnore\n\tpublic String secret;"));
+    assert!(!user.contains("serde"));
+}
+
+#[test]
+fn deprecations_are_annotated_and_documented() {
+    let run = map(
+        "java_deprecations",
+        "targets [ java ]\n\
+         @deprecated(\"use User\")\n\
+         pub Person { @deprecated(\"use fullName\") pub name: String }",
+        &[],
+    );
+    let person = run.read("Person.java");
+    assert!(
+        person.starts_with("/**\n * @deprecated use User\n */\n@Deprecated\npublic class Person {")
+    );
+    assert!(person.contains(
+        "\t/**\n\t * @deprecated use fullName\n\t */\n\t@Deprecated\n\tpublic String name;"
+    ));
+}
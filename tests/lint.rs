@@ -0,0 +1,93 @@
+mod common;
+
+use common::{map, run};
+
+#[test]
+fn reports_style_issues_with_their_line() {
+    let run = map(
+        "lint_style",
+        "targets [ rs ]\n\
+         pub user_account { pub home_address: Address }\n\
+         pub Address {\n    pub street: String,\n    pub Street: String\n}\n\
+         pub Empty { }",
+        &[],
+    );
+    assert!(run.stdout.contains("[class-case] "));
+    assert!(run
+        .stdout
+        .contains("schema.txt:2: `user_account` should be PascalCase"));
+    assert!(run
+        .stdout
+        .contains("schema.txt:2: field `home_address` of `user_account` should be camelCase"));
+    assert!(run.stdout.contains(
+        "schema.txt:5: field `Street` of `Address` only differs from `street` (line 4) by case"
+    ));
+    assert!(run
+        .stdout
+        .contains("schema.txt:7: `Empty` declares no fields"));
+    // off by default
+    assert!(!run.stdout.contains("[explicit-access]"));
+}
+
+#[test]
+fn reports_unused_classes_of_every_file() {
+    let run = run(
+        "lint_unused",
+        &[
+            (
+                "main.txt",
+                "import \"types.txt\"\ntargets [ rs ]\npub User { pub home: Address }\npub Orphan { pub a: int }",
+            ),
+            (
+                "types.txt",
+                "targets [ rs ]\npub Address { pub street: String }\npub Unused { pub b: int }",
+            ),
+        ],
+        &["main.txt"],
+    );
+    assert!(run.success, "mapping failed:\n{}", run.stdout);
+    assert!(run
+        .stdout
+        .contains("main.txt:4: `Orphan` is not used by any other class"));
+    assert!(run
+        .stdout
+        .contains("types.txt:3: `Unused` is not used by any other class"));
+    assert!(!run.stdout.contains("`Address` is not used"));
+}
+
+#[test]
+fn allow_comments_silence_the_next_item_or_their_own_line() {
+    let run = map(
+        "lint_allow",
+        "targets [ rs ]\n\
+         // allow(class-case, unused-class)\n\
+         pub user_account {\n    \
+             pub home_address: Address, // allow(field-case)\n    \
+             pub other_field: int\n}\n\
+         pub Address { pub street: String }",
+        &[],
+    );
+    assert!(!run.stdout.contains("`user_account` should be PascalCase"));
+    assert!(!run.stdout.contains("`user_account` is not used"));
+    assert!(!run.stdout.contains("`home_address` of"));
+    assert!(run.stdout.contains("schema.txt:5: field `other_field`"));
+}
+
+#[test]
+fn levels_can_turn_rules_into_errors() {
+    let run = run(
+        "lint_error",
+        &[("schema.txt", "[ rs ]\npub user { pub a: int }")],
+        &[
+            "--lint",
+            "class-case=error,unused-class=allow",
+            "schema.txt",
+        ],
+    );
+    assert!(!run.success);
+    assert!(run
+        .stdout
+        .contains("schema.txt:2: `user` should be PascalCase"));
+    assert!(run.stdout.contains("1 lint error(s) found."));
+    assert!(!run.stdout.contains("[unused-class]"));
+}
@@ -0,0 +1,349 @@
+mod common;
+
+use common::{map, run, squash};
+
+#[test]
+fn every_struct_implements_default() {
+    let run = map(
+        "rs_default",
+        "targets [ rs ]\n\
+         pub User { pub age: int = 18, pub home: Address, pub shape: Shape, pub owner: Owner }\n\
+         pub Address { pub street: String, pub at: datetime }\n\
+         pub Plain { pub a: int, pub b: List<Address> }\n\
+         pub Circle { pub r: double }\n\
+         pub union Shape(kind) { circle: Circle }\n\
+         pub type Owner = Address",
+        &[],
+    );
+    let user = squash(&run.read("User.rs"));
+    assert!(user.contains("impl Default for User"));
+    assert!(user.contains("age: 18, home: Default::default(),"));
+    // no schema defaults but a field without a `Default` of its own
+    let address = squash(&run.read("Address.rs"));
+    assert!(address.contains("impl Default for Address"));
+    assert!(address.contains("at: DateTime::from(std::time::UNIX_EPOCH),"));
+    assert!(squash(&run.read("Plain.rs")).contains("Default)] pub struct Plain"));
+    assert!(squash(&run.read("Shape.rs")).contains(
+        "impl Default for Shape { fn default() -> Self { Shape::Circle(Default::default())"
+    ));
+    assert!(
+        squash(&run.read("Owner.rs")).contains("Default)] #[serde(transparent)] pub struct Owner")
+    );
+}
+
+#[test]
+fn indirect_fields_default_to_none() {
+    let run = map(
+        "rs_default_indirect",
+        "[ rs ]\npub Node { pub next: Node, pub value: int = 1 }",
+        &[],
+    );
+    let node = squash(&run.read("Node.rs"));
+    assert!(node.contains("pub next: Option<Box<Node>>,"));
+    assert!(node.contains("next: Default::default(), value: 1,"));
+}
+
+#[test]
+fn every_type_is_serializable() {
+    let run = map(
+        "rs_serde",
+        "targets [ rs ]\n\
+         pub User { pub isUsed: bool, pub home: Address }\n\
+         pub Address { pub street: String }\n\
+         pub interface Named { name: String }",
+        &[],
+    );
+    let user = squash(&run.read("User.rs"));
+    assert!(
+        user.contains("#[derive(serde::Serialize, serde::Deserialize, Default)] pub struct User")
+    );
+    assert!(user.contains("#[serde(rename = \"isUsed\")] pub is_used: bool,"));
+    assert!(squash(&run.read("Address.rs"))
+        .contains("#[derive(serde::Serialize, serde::Deserialize, Default)] pub struct Address"));
+    assert!(!run.read("Named.rs").contains("derive"));
+}
+
+#[test]
+fn long_arrays_are_serialized_as_sequences() {
+    let run = map(
+        "rs_big_array",
+        "[ rs ]\npub Buffer { pub small: [u8; 32], pub big: [u8; 33] }",
+        &[],
+    );
+    let buffer = squash(&run.read("Buffer.rs"));
+    assert!(buffer.contains("mod big_array {"));
+    assert!(buffer.contains("pub small: [u8; 32],"));
+    assert!(buffer.contains("#[serde(with = \"big_array\")] pub big: [u8; 33],"));
+    let run = map(
+        "rs_small_array",
+        "[ rs ]\npub Buffer { pub small: [u8; 32] }",
+        &[],
+    );
+    assert!(!run.read("Buffer.rs").contains("big_array"));
+}
+
+#[test]
+fn types_of_other_namespaces_are_used_by_path() {
+    let run = run(
+        "rs_namespaces",
+        &[
+            (
+                "users.txt",
+                "namespace com.acme.users;\n\
+                 import \"places.txt\"\n\
+                 targets [ rs ]\n\
+                 pub User { pub home: Address, pub friend: User }",
+            ),
+            (
+                "places.txt",
+                "namespace com.acme.places;\n[ rs ]\npub Address { pub street: String }",
+            ),
+        ],
+        &["users.txt"],
+    );
+    assert!(run.success, "mapping failed:\n{}", run.stdout);
+    let user = squash(&run.read("User.rs"));
+    assert!(user.starts_with(
+        "pub mod com { pub mod acme { pub mod users { use crate::com::acme::places::Address; #["
+    ));
+    assert!(!user.contains("use crate::com::acme::users::User"));
+    assert!(!run.read("Address.rs").contains("use "));
+}
+
+#[test]
+fn patterns_are_compiled_once() {
+    let run = map(
+        "rs_patterns",
+        "[ rs ]\npub User { @pattern(\"^[a-z]+$\") @pattern(\"^.{3,}\") pub name: String }",
+        &[],
+    );
+    let user = squash(&run.read("User.rs"));
+    assert!(user.contains(
+        "static NAME_PATTERN: std::sync::LazyLock<regex::Regex> = \
+         std::sync::LazyLock::new(|| regex::Regex::new(r#\"^[a-z]+$\"#).unwrap());"
+    ));
+    assert!(user.contains("static NAME_PATTERN_2"));
+    assert!(user.contains("if !NAME_PATTERN.is_match(&self.name) {"));
+    assert!(user.contains("if !NAME_PATTERN_2.is_match(&self.name) {"));
+}
+
+#[test]
+fn interfaces_become_traits() {
+    let run = map(
+        "rs_interfaces",
+        "targets [ rs ]\n\
+         pub interface Named { name: String }\n\
+         pub User implements Named { pub name: String, pub age: int }",
+        &[],
+    );
+    assert!(
+        squash(&run.read("Named.rs")).contains("pub trait Named { fn name(&self) -> &String; }")
+    );
+    assert!(squash(&run.read("User.rs"))
+        .contains("impl Named for User { fn name(&self) -> &String { &self.name } }"));
+}
+
+#[test]
+fn generics_keep_their_type_parameters() {
+    let run = map(
+        "rs_generics",
+        "targets [ rs ]\n\
+         pub Page<T> { pub items: List<T>, pub first: T }\n\
+         pub User { pub page: Page<Address> }\n\
+         pub Address { pub street: String }",
+        &[],
+    );
+    assert!(squash(&run.read("Page.rs"))
+        .contains("pub struct Page<T> { pub items: Vec<T>, pub first: T, }"));
+    assert!(squash(&run.read("User.rs")).contains("pub page: Page<Address>,"));
+}
+
+#[test]
+fn doc_comments_are_kept_and_plain_comments_dropped() {
+    let run = map(
+        "rs_comments",
+        "targets [ rs ]\n\
+         // not documented\n\
+         /// A user\n\
+         /// of the site\n\
+         pub User {\n    /// login name\n    pub name: String, // trailing\n    /* block */ pub age: int\n}",
+        &[],
+    );
+    let user = run.read("User.rs");
+    assert!(user.starts_with("/// A user\n/// of the site\n#[derive("));
+    assert!(user.contains("\t/// login name\n\tpub name: String,\n\tpub age: i32,"));
+    assert!(!user.contains("documented"));
+    assert!(!user.contains("trailing"));
+}
+
+#[test]
+fn imported_types_are_written_once() {
+    let run = run(
+        "rs_imports",
+        &[
+            (
+                "a.txt",
+                "import \"types.txt\"\ntargets [ rs ]\npub A { pub home: Address }",
+            ),
+            (
+                "b.txt",
+                "import \"types.txt\"\ntargets [ rs ]\npub B { pub home: Address }",
+            ),
+            (
+                "types.txt",
+                "targets [ rs ]\npub Address { pub street: String }",
+            ),
+        ],
+        &["a.txt", "b.txt"],
+    );
+    assert!(run.success, "mapping failed:\n{}", run.stdout);
+    assert_eq!(
+        run.stdout
+            .matches("Beginning to write to file \"Address.rs\"")
+            .count(),
+        1
+    );
+    assert!(run.exists("A.rs") && run.exists("B.rs"));
+}
+
+#[test]
+fn overridden_names_keep_the_schema_name_on_the_wire() {
+    let run = map(
+        "rs_renames",
+        "targets [ rs ]\n\
+         @name(rs = \"Person\")\n\
+         pub User { @rename(rs = \"ident\") pub id: String }\n\
+         pub Holder { pub user: User }",
+        &[],
+    );
+    assert!(!run.exists("User.rs"));
+    assert!(squash(&run.read("Person.rs"))
+        .contains("pub struct Person { #[serde(rename = \"id\")] pub ident: String, }"));
+    assert!(squash(&run.read("Holder.rs")).contains("pub user: Person,"));
+}
+
+#[test]
+fn keywords_are_escaped_and_keep_their_wire_names() {
+    let run = map(
+        "rs_keywords",
+        "targets [ rs ]\npub User { pub type: String, pub self: int, pub class: int }",
+        &[],
+    );
+    let user = squash(&run.read("User.rs"));
+    assert!(user.contains("#[serde(rename = \"type\")] pub r#type: String,"));
+    assert!(user.contains("#[serde(rename = \"self\")] pub self_: i32,"));
+    assert!(user.contains("pub class: i32,"));
+    assert!(run
+        .stdout
+        .contains("Escaping reserved keyword \"type\" in User.type as \"r#type\""));
+}
+
+#[test]
+fn built_in_types_use_the_chosen_crates() {
+    let run = map(
+        "rs_built_ins",
+        "targets [ rs ]\npub Times { pub at: datetime, pub id: uuid, pub data: bytes }",
+        &[],
+    );
+    let times = squash(&run.read("Times.rs"));
+    assert!(times.contains("type DateTime = std::time::SystemTime; type Uuid = String;"));
+    assert!(times.contains("pub at: DateTime, pub id: Uuid, pub data: Vec<u8>,"));
+    let run = map(
+        "rs_built_ins_crates",
+        "targets [ rs ]\npub Times { pub at: datetime, pub id: uuid }",
+        &["--rs-crates", "chrono,uuid"],
+    );
+    let times = squash(&run.read("Times.rs"));
+    assert!(times.contains("type DateTime = chrono::DateTime<chrono::Utc>;"));
+    assert!(times.contains("type Uuid = uuid::Uuid;"));
+}
+
+#[test]
+fn unions_are_internally_tagged_enums() {
+    let run = map(
+        "rs_unions",
+        "targets [ rs ]\n\
+         pub Circle { pub r: double }\n\
+         pub Square { pub side: double }\n\
+         pub union Shape(kind) { circle: Circle, square: Square }",
+        &[],
+    );
+    assert!(squash(&run.read("Shape.rs")).contains(
+        "#[serde(tag = \"kind\")] pub enum Shape { #[serde(rename = \"circle\")] Circle(Circle), \
+         #[serde(rename = \"square\")] Square(Square), }"
+    ));
+}
+
+#[test]
+fn aliases_are_newtypes_unless_asked_otherwise() {
+    let schema = "targets [ rs ]\n\
+                  pub type UserId = String\n\
+                  pub User { @length(1, 8) pub id: UserId }";
+    let run = map("rs_aliases", schema, &[]);
+    assert!(squash(&run.read("UserId.rs")).contains(
+        "#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Default)] \
+         #[serde(transparent)] pub struct UserId(pub String);"
+    ));
+    assert!(squash(&run.read("User.rs")).contains("if self.id.0.chars().count() < 1 {"));
+    let run = map("rs_aliases_plain", schema, &["--rs-aliases", "alias"]);
+    assert!(squash(&run.read("UserId.rs")).contains("pub type UserId = String;"));
+    assert!(squash(&run.read("User.rs")).contains("if self.id.chars().count() < 1 {"));
+}
+
+#[test]
+fn constants_are_consts_and_inlined() {
+    let run = map(
+        "rs_constants",
+        "targets [ rs ]\n\
+         pub const MAX_AGE: int = 150\n\
+         pub const SIZE: int = 4\n\
+         pub const GREETING: String = \"hi\"\n\
+         pub User { @max(MAX_AGE) pub age: int = MAX_AGE, pub grid: [int; SIZE] }",
+        &[],
+    );
+    assert!(squash(&run.read("MAX_AGE.rs")).contains("pub const MAX_AGE: i32 = 150;"));
+    assert!(squash(&run.read("GREETING.rs")).contains("pub const GREETING: &str = \"hi\";"));
+    let user = squash(&run.read("User.rs"));
+    assert!(user.contains("pub grid: [i32; 4],"));
+    assert!(user.contains("age: 150,"));
+    assert!(user.contains("if self.age > 150 {"));
+}
+
+#[test]
+fn tuples_are_rust_tuples() {
+    let run = map(
+        "rs_tuples",
+        "targets [ rs ]\npub User { pub pair: (int, String) }",
+        &[],
+    );
+    assert!(squash(&run.read("User.rs")).contains("pub pair: (i32, String),"));
+}
+
+#[test]
+fn attributes_are_emitted_verbatim() {
+    let run = map(
+        "rs_attributes",
+        "targets [ rs, java ]\n\
+         @rs(\"#[serde(deny_unknown_fields)]\")\n\
+         pub User { @rs(\"#[serde(skip)]\") @java(\"@JsonIgnore\") pub secret: String }",
+        &[],
+    );
+    let user = run.read("User.rs");
+    assert!(user.contains("#[serde(deny_unknown_fields)]\npub struct User {"));
+    assert!(user.contains("\t#[serde(skip)]\n\tpub secret: String,"));
+    assert!(!user.contains("JsonIgnore"));
+}
+
+#[test]
+fn deprecations_are_attributes() {
+    let run = map(
+        "rs_deprecations",
+        "targets [ rs ]\n\
+         @deprecated(\"use User\")\n\
+         pub Person { @deprecated(\"use fullName\") pub name: String }",
+        &[],
+    );
+    let person = run.read("Person.rs");
+    assert!(person.contains("#[deprecated(note = \"use User\")]\npub struct Person {"));
+    assert!(person.contains("\t#[deprecated(note = \"use fullName\")]\n\tpub name: String,"));
+}
@@ -0,0 +1,272 @@
+mod common;
+
+use common::{map, run, squash};
+
+#[test]
+fn fields_are_camel_case_and_keep_their_wire_names() {
+    let run = map(
+        "ts_wire_names",
+        "[ ts ]\npub User { pub is_used: bool = true, priv secret_key: String, pub age: int }",
+        &[],
+    );
+    let user = squash(&run.read("User.ts"));
+    assert!(user.contains("public isUsed: boolean = true;"));
+    assert!(user.contains("private secretKey: string;"));
+    assert!(user.contains(
+        "toJSON(): Record<string, unknown> { const { isUsed, secretKey, ...json } = this; \
+         return { ...json, \"is_used\": isUsed, \"secret_key\": secretKey }; }"
+    ));
+    assert!(user.contains(
+        "static fromJSON(json: Record<string, any>): User { \
+         const { \"is_used\": isUsed, \"secret_key\": secretKey, ...fields } = json; \
+         const value: User = Object.assign(new User(), fields); \
+         value.isUsed = isUsed; value.secretKey = secretKey; return value; }"
+    ));
+}
+
+#[test]
+fn classes_without_renamed_fields_are_serialized_as_they_are() {
+    let run = map(
+        "ts_no_wire_names",
+        "[ ts ]\npub User { pub name: String }",
+        &[],
+    );
+    let user = run.read("User.ts");
+    assert!(!user.contains("toJSON"));
+    assert!(!user.contains("fromJSON"));
+}
+
+#[test]
+fn nested_classes_are_read_through_their_from_json() {
+    let run = map(
+        "ts_nested_from_json",
+        "targets [ ts ]\n\
+         pub Team { pub lead: User, pub members: List<User>, pub pair: [User; 2], pub name: String }\n\
+         pub User { pub user_name: String }",
+        &[],
+    );
+    let team = squash(&run.read("Team.ts"));
+    assert!(!team.contains("toJSON"));
+    assert!(team.contains("value.lead = User.fromJSON(lead);"));
+    assert!(team.contains("value.members = members.map((item: any) => User.fromJSON(item));"));
+    assert!(
+        team.contains("value.pair = pair.map((item: any) => User.fromJSON(item)) as [User, User];")
+    );
+    assert!(!team.contains("value.name"));
+}
+
+#[test]
+fn case_can_be_overridden() {
+    let run = map(
+        "ts_case_override",
+        "[ ts ]\npub User { pub isUsed: bool }",
+        &["--case", "ts=snake"],
+    );
+    let user = squash(&run.read("User.ts"));
+    assert!(user.contains("public is_used: boolean;"));
+    assert!(user.contains("return { ...json, \"isUsed\": is_used };"));
+}
+
+#[test]
+fn imported_declarations_are_exported() {
+    let run = map(
+        "ts_exports",
+        "targets [ ts ]\n\
+         pub interface Named { name: String }\n\
+         Circle implements Named { pub name: String, pub r: double }\n\
+         priv Square { pub side: double }\n\
+         union Shape(kind) { circle: Circle, square: Square }\n\
+         type Id = String\n\
+         const LIMIT: int = 3",
+        &[],
+    );
+    let circle = squash(&run.read("Circle.ts"));
+    assert!(circle.contains("import { Named } from \"./Named\";"));
+    assert!(circle.contains("export class Circle implements Named {"));
+    assert!(squash(&run.read("Named.ts")).contains("export interface Named {"));
+    assert!(squash(&run.read("Square.ts")).contains("export class Square {"));
+    let shape = squash(&run.read("Shape.ts"));
+    assert!(
+        shape.contains("import { Circle } from \"./Circle\"; import { Square } from \"./Square\";")
+    );
+    assert!(shape.contains("export type Shape ="));
+    assert!(squash(&run.read("Id.ts")).contains("export type Id = string;"));
+    assert!(squash(&run.read("LIMIT.ts")).contains("export const LIMIT: number = 3;"));
+}
+
+#[test]
+fn referenced_modules_are_imported_by_relative_path() {
+    let run = run(
+        "ts_namespaces",
+        &[
+            (
+                "users.txt",
+                "namespace com.acme.users;\n\
+                 import \"places.txt\"\n\
+                 targets [ ts ]\n\
+                 pub User { pub home: Address, pub manager: Manager, pub pages: Page<Address> }\n\
+                 pub Manager { pub name: String }",
+            ),
+            (
+                "places.txt",
+                "namespace com.acme.places;\n\
+                 targets [ ts ]\n\
+                 pub Address { pub street: String }\n\
+                 pub Page<T> { pub items: List<T> }",
+            ),
+        ],
+        &["users.txt"],
+    );
+    assert!(run.success, "mapping failed:\n{}", run.stdout);
+    let user = squash(&run.read("com/acme/users/User.ts"));
+    assert!(user.starts_with(
+        "import { Address } from \"../places/Address\"; \
+         import { Manager } from \"./Manager\"; \
+         import { Page } from \"../places/Page\"; export class User {"
+    ));
+    // type parameters are not modules
+    assert!(!run.read("com/acme/places/Page.ts").contains("import"));
+}
+
+#[test]
+fn defaults_are_field_initializers() {
+    let run = map(
+        "ts_defaults",
+        "targets [ ts ]\npub User { pub age: int = 5, pub tags: List<String> = [\"x\"], pub plain: int }",
+        &[],
+    );
+    assert!(squash(&run.read("User.ts")).contains(
+        "public age: number = 5; public tags: Array<string> = [\"x\"]; public plain: number;"
+    ));
+}
+
+#[test]
+fn constraints_are_checked_by_validate() {
+    let run = map(
+        "ts_constraints",
+        "targets [ ts ]\npub User { @min(0) pub age: int, @length(1, 64) @pattern(\"^\\\\w+$\") pub name: String }",
+        &[],
+    );
+    let user = squash(&run.read("User.ts"));
+    assert!(user.contains("validate(): string[] { const errors: string[] = [];"));
+    assert!(user.contains("if (this.age < 0) errors.push(\"age must be at least 0\");"));
+    assert!(user.contains(
+        "if (this.name.length > 64) errors.push(\"name must have a length of at most 64\");"
+    ));
+    // the backslash is escaped inside the string literal
+    assert!(user.contains("if (!new RegExp(\"^\\\\\\\\w+$\").test(this.name))"));
+}
+
+#[test]
+fn renamed_fields_keep_the_schema_name_on_the_wire() {
+    let run = map(
+        "ts_renames",
+        "targets [ ts ]\npub User { @rename(ts = \"identifier\") pub id: String }",
+        &[],
+    );
+    let user = squash(&run.read("User.ts"));
+    assert!(user.contains("public identifier: string;"));
+    assert!(user.contains("return { ...json, \"id\": identifier };"));
+}
+
+#[test]
+fn keywords_are_valid_property_names() {
+    let run = map(
+        "ts_keywords",
+        "targets [ ts ]\npub User { pub class: int }",
+        &[],
+    );
+    assert!(run.read("User.ts").contains("public class: number;"));
+    assert!(!run.stdout.contains("Escaping"));
+}
+
+#[test]
+fn wide_integers_are_bigints() {
+    let run = map(
+        "ts_widths",
+        "targets [ ts ]\npub Widths { pub a: i8, pub c: i64, pub d: u64 }",
+        &[],
+    );
+    assert!(squash(&run.read("Widths.ts"))
+        .contains("public a: number; public c: bigint; public d: bigint;"));
+}
+
+#[test]
+fn short_fixed_arrays_are_tuples() {
+    let run = map(
+        "ts_fixed_arrays",
+        "targets [ ts ]\npub Grid { pub cells: [int; 3], pub big: [int; 17] }",
+        &[],
+    );
+    let grid = squash(&run.read("Grid.ts"));
+    assert!(
+        grid.contains("static readonly CELLS_LENGTH = 3; public cells: [number, number, number];")
+    );
+    assert!(grid.contains("public big: number[];"));
+}
+
+#[test]
+fn unions_are_discriminated_types() {
+    let run = map(
+        "ts_unions",
+        "targets [ ts ]\n\
+         pub Circle { pub r: double }\n\
+         pub union Shape(kind) { circle: Circle }",
+        &[],
+    );
+    assert!(squash(&run.read("Shape.ts"))
+        .contains("export type Shape = | ({ kind: \"circle\" } & Circle);"));
+}
+
+#[test]
+fn constants_are_exported_and_inlined() {
+    let run = map(
+        "ts_constants",
+        "targets [ ts ]\n\
+         pub const MAX_AGE: int = 150\n\
+         pub const GREETING: String = \"hi\"\n\
+         pub User { @max(MAX_AGE) pub age: int = MAX_AGE }",
+        &[],
+    );
+    assert!(squash(&run.read("MAX_AGE.ts")).contains("export const MAX_AGE: number = 150;"));
+    assert!(squash(&run.read("GREETING.ts")).contains("export const GREETING: string = \"hi\";"));
+    let user = squash(&run.read("User.ts"));
+    assert!(user.contains("public age: number = 150;"));
+    assert!(user.contains("if (this.age > 150)"));
+}
+
+#[test]
+fn tuples_are_typescript_tuples() {
+    let run = map(
+        "ts_tuples",
+        "targets [ ts ]\npub User { pub pair: (int, String) }",
+        &[],
+    );
+    assert!(squash(&run.read("User.ts")).contains("public pair: [number, string];"));
+}
+
+#[test]
+fn attributes_are_emitted_verbatim() {
+    let run = map(
+        "ts_attributes",
+        "targets [ ts ]\npub User { @ts(\"// kept\") pub secret: String }",
+        &[],
+    );
+    assert!(run
+        .read("User.ts")
+        .contains("\t// kept\n\tpublic secret: string;"));
+}
+
+#[test]
+fn deprecations_are_documented() {
+    let run = map(
+        "ts_deprecations",
+        "targets [ ts ]\n\
+         @deprecated(\"use User\")\n\
+         pub Person { @deprecated(\"use fullName\") pub name: String }",
+        &[],
+    );
+    let person = run.read("Person.ts");
+    assert!(person.starts_with("/**\n * @deprecated use User\n */\nexport class Person {"));
+    assert!(person.contains("\t/**\n\t * @deprecated use fullName\n\t */\n\tpublic name: string;"));
+}